@@ -4,7 +4,10 @@ use crate::{error::OsuError, OsuResult};
 use hyper::client::Builder;
 use hyper_rustls::HttpsConnectorBuilder;
 use leaky_bucket_lite::LeakyBucket;
-use std::{sync::Arc, time::Duration};
+use std::{
+    sync::{atomic::AtomicU32, Arc},
+    time::Duration,
+};
 use tokio::sync::{oneshot, RwLock};
 
 #[cfg(feature = "cache")]
@@ -91,6 +94,7 @@ impl OsuBuilder {
             auth_kind: self.auth_kind.unwrap_or_default(),
             token: RwLock::new(Token::default()),
             retries: self.retries,
+            own_user_id: AtomicU32::new(0),
         });
 
         // Acquire the initial API token
@@ -113,6 +117,9 @@ impl OsuBuilder {
             #[cfg(feature = "cache")]
             cache: Arc::new(DashMap::new()),
 
+            #[cfg(feature = "cache")]
+            missing_checksums: Arc::new(DashMap::new()),
+
             #[cfg(feature = "metrics")]
             metrics: Arc::new(Metrics::new()),
         })
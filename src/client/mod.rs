@@ -7,12 +7,19 @@ use token::{Authorization, AuthorizationKind, Token, TokenResponse};
 pub use builder::OsuBuilder;
 pub use token::Scope;
 
-use crate::{error::OsuError, model::GameMode, request::*, OsuResult};
+use crate::{
+    error::OsuError,
+    model::{GameMode, GameMods},
+    request::*,
+    OsuResult,
+};
 
 use hyper::{
     body::{Body as HyperBody, HttpBody, SizeHint},
     client::{Client as HyperClient, HttpConnector},
-    header::{HeaderValue, ACCEPT, AUTHORIZATION, CONTENT_LENGTH, CONTENT_TYPE, USER_AGENT},
+    header::{
+        HeaderValue, ACCEPT, AUTHORIZATION, CONTENT_LENGTH, CONTENT_TYPE, LOCATION, USER_AGENT,
+    },
     HeaderMap, Method, Request as HyperRequest, Response, StatusCode,
 };
 use hyper_rustls::HttpsConnector;
@@ -23,7 +30,10 @@ use std::{
     mem,
     ops::Drop,
     pin::Pin,
-    sync::Arc,
+    sync::{
+        atomic::{AtomicU32, Ordering},
+        Arc,
+    },
     task::{Context, Poll},
     time::Duration,
 };
@@ -31,17 +41,27 @@ use tokio::sync::{oneshot::Sender, RwLock};
 use url::Url;
 
 #[cfg(feature = "cache")]
-use {crate::prelude::Username, dashmap::DashMap};
+use {crate::prelude::Username, dashmap::DashMap, std::time::Instant};
 
 #[cfg(feature = "metrics")]
 use {crate::metrics::Metrics, prometheus::IntCounterVec};
 
+/// Duration for which a checksum without a map is not requested again
+#[cfg(feature = "cache")]
+const MISSING_CHECKSUM_TTL: Duration = Duration::from_secs(60 * 60);
+
+/// Maximum amount of remembered checksums without a map
+#[cfg(feature = "cache")]
+const MISSING_CHECKSUMS_CAP: usize = 10_000;
+
 /// The main osu client.
 /// Cheap to clone.
 pub struct Osu {
     pub(crate) inner: Arc<OsuRef>,
     #[cfg(feature = "cache")]
     pub(crate) cache: Arc<DashMap<Username, u32>>,
+    #[cfg(feature = "cache")]
+    pub(crate) missing_checksums: Arc<DashMap<String, Instant>>,
     #[cfg(feature = "metrics")]
     pub(crate) metrics: Arc<Metrics>,
     token_loop_tx: Option<Sender<()>>,
@@ -98,24 +118,93 @@ impl Osu {
         GetBeatmaps::new(self, map_ids)
     }
 
-    /// Get a vec of [`Score`](crate::model::score::Score).
+    /// Resolve beatmap checksums to [`Beatmap`](crate::model::beatmap::Beatmap)s.
+    ///
+    /// Checksums are lowercased and each unique checksum is looked up through
+    /// its own request, all of which run concurrently within the ratelimit.
+    /// The result of each request is stored separately so that one failed
+    /// request does not discard the others.
+    /// Checksums without a map on the osu! servers are mapped to `Ok(None)`.
+    ///
+    /// With the `cache` feature, up to 10,000 checksums without a map are
+    /// remembered and not requested again for an hour.
+    /// Use [`Osu::clear_missing_checksums`] to forget them early.
+    #[inline]
+    pub fn beatmaps_by_checksums<I, S>(&self, checksums: I) -> GetBeatmapsByChecksums<'_>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        GetBeatmapsByChecksums::new(self, checksums)
+    }
+
+    /// Get the [`BeatmapDifficultyAttributes`](crate::model::beatmap::BeatmapDifficultyAttributes)
+    /// of multiple maps for multiple mod combinations, keyed by map id and then by mods.
+    ///
+    /// Combinations that don't change the star rating share a single request per map.
+    #[inline]
+    pub fn beatmaps_difficulty_attributes<I, M>(
+        &self,
+        map_ids: I,
+        mods: M,
+    ) -> GetBeatmapsDifficultyAttributes<'_>
+    where
+        I: IntoIterator<Item = u32>,
+        M: IntoIterator<Item = GameMods>,
+    {
+        GetBeatmapsDifficultyAttributes::new(self, map_ids, mods)
+    }
+
+    /// Get the leaderboard of a beatmap in form of
+    /// [`BeatmapScores`](crate::model::score::BeatmapScores).
     ///
     /// The contained scores will have the following options filled:
     /// `pp` (if ranked or approved), and `user`.
     ///
     /// The scores' contained [`UserCompact`](crate::model::user::UserCompact)
     /// will have the `country` and `cover` options filled.
+    ///
+    /// If the client is authenticated through OAuth, the leaderboard
+    /// also contains the user's own score.
     #[inline]
     pub fn beatmap_scores(&self, map_id: u32) -> GetBeatmapScores<'_> {
         GetBeatmapScores::new(self, map_id)
     }
 
+    /// Get a [`BeatmapPack`](crate::model::beatmap::BeatmapPack) by its tag.
+    ///
+    /// The pack will have its `mapsets` option filled and, if the client
+    /// is authenticated through OAuth, also the `user_completion` option.
+    #[inline]
+    pub fn beatmap_pack(&self, tag: impl Into<String>) -> GetBeatmapPack<'_> {
+        GetBeatmapPack::new(self, tag)
+    }
+
+    /// Get [`BeatmapPacks`](crate::model::beatmap::BeatmapPacks).
+    #[inline]
+    pub fn beatmap_packs(&self) -> GetBeatmapPacks<'_> {
+        GetBeatmapPacks::new(self)
+    }
+
     /// Get the [`BeatmapDifficultyAttributes`](crate::model::beatmap::BeatmapDifficultyAttributes) for a map.
     #[inline]
     pub fn beatmap_difficulty_attributes(&self, map_id: u32) -> GetBeatmapDifficultyAttributes<'_> {
         GetBeatmapDifficultyAttributes::new(self, map_id)
     }
 
+    /// Get the [`BeatmapDifficultyAttributes`](crate::model::beatmap::BeatmapDifficultyAttributes)
+    /// of a map for multiple mod combinations, keyed by the mods.
+    ///
+    /// Combinations that don't change the star rating share a single request.
+    #[inline]
+    pub fn beatmap_difficulty_attributes_batch(
+        &self,
+        map_id: u32,
+        mods: impl IntoIterator<Item = GameMods>,
+    ) -> GetBeatmapDifficultyAttributesBatch<'_> {
+        GetBeatmapDifficultyAttributesBatch::new(self, map_id, mods)
+    }
+
     /// Get a [`BeatmapUserScore`](crate::model::score::BeatmapUserScore).
     ///
     /// The contained [`Score`](crate::model::score::Score) will have the
@@ -181,6 +270,16 @@ impl Osu {
         GetBeatmapset::new(self, mapset_id)
     }
 
+    /// Download the `.osz` archive of a [`Beatmapset`](crate::model::beatmap::Beatmapset)
+    /// as a stream of bytes.
+    ///
+    /// Note that this endpoint is not part of the documented API
+    /// and may not be available for all kinds of authorization.
+    #[inline]
+    pub fn beatmapset_download(&self, mapset_id: u32) -> GetBeatmapsetDownload<'_> {
+        GetBeatmapsetDownload::new(self, mapset_id)
+    }
+
     /// Get a [`Beatmapset`](crate::model::beatmap::Beatmapset) from a map ID.
     ///
     /// Filled options will be: `artist_unicode`, `converts`, `description`,
@@ -268,6 +367,33 @@ impl Osu {
         GetCountryRankings::new(self, mode)
     }
 
+    /// Get the [`Room`](crate::model::room::Room) of the current daily challenge.
+    ///
+    /// If there is currently no daily challenge, `None` is returned.
+    #[inline]
+    pub fn daily_challenge(&self) -> GetDailyChallenge<'_> {
+        GetDailyChallenge::new(self)
+    }
+
+    /// Get [`Events`](crate::model::recent_event::Events) i.e. a site-wide feed
+    /// of [`RecentEvent`](crate::model::recent_event::RecentEvent)s.
+    #[inline]
+    pub fn events(&self) -> GetEvents<'_> {
+        GetEvents::new(self)
+    }
+
+    /// Favourite a [`Beatmapset`](crate::model::beatmap::Beatmapset)
+    /// for the authenticated user and get its updated `favourite_count`.
+    ///
+    /// Note that the client has to be initialized through the OAuth process
+    /// in order for this endpoint to not return an error.
+    ///
+    /// See [`OsuBuilder::with_authorization`](crate::OsuBuilder::with_authorization).
+    #[inline]
+    pub fn favourite_beatmapset(&self, mapset_id: u32) -> FavouriteBeatmapset<'_> {
+        FavouriteBeatmapset::new(self, mapset_id, true)
+    }
+
     /// Get a [`ForumPosts`](crate::model::forum::ForumPosts) struct for a forum topic
     #[inline]
     pub fn forum_posts(&self, topic_id: u64) -> GetForumPosts<'_> {
@@ -290,6 +416,16 @@ impl Osu {
         GetUserKudosu::new(self, user_id.into())
     }
 
+    /// Get a [`KudosuRankings`](crate::model::ranking::KudosuRankings) struct whose
+    /// [`UserCompact`](crate::model::user::UserCompact)s are sorted
+    /// by their total kudosu, i.e. the current kudosu leaderboard.
+    ///
+    /// The users will have the `kudosu` option filled.
+    #[inline]
+    pub fn kudosu_rankings(&self) -> GetKudosuRankings<'_> {
+        GetKudosuRankings::new(self)
+    }
+
     /// Get [`News`](crate::model::news::News).
     #[inline]
     pub fn news(&self) -> GetNews<'_> {
@@ -302,6 +438,16 @@ impl Osu {
         GetMatch::new(self, match_id)
     }
 
+    /// Get the [`MatchEvent`](crate::model::matches::MatchEvent)s of a multiplayer match
+    /// in form of a [`JsonArrayStream`](crate::request::JsonArrayStream).
+    ///
+    /// The events are deserialized one by one while the response is received
+    /// so the full response is never held in memory.
+    #[inline]
+    pub fn osu_match_events(&self, match_id: u32) -> GetMatchEvents<'_> {
+        GetMatchEvents::new(self, match_id)
+    }
+
     /// Get a [`MatchList`](crate::model::matches::MatchList) containing all
     /// currently open multiplayer lobbies.
     #[inline]
@@ -320,6 +466,24 @@ impl Osu {
         GetOwnData::new(self)
     }
 
+    /// Get a vec of [`Beatmapset`](crate::model::beatmap::Beatmapset)s
+    /// the authenticated user favourited.
+    ///
+    /// Filled options will be: `artist_unicode`, `legacy_thread_url`, `maps`, `title_unicode`.
+    ///
+    /// The endpoint requires the id of the authenticated user so, unless it's
+    /// already known from a previous [`own_data`](Osu::own_data) or
+    /// `own_favourites` call, it will be requested first.
+    ///
+    /// Note that the client has to be initialized with the `identify` scope
+    /// through the OAuth process in order for this endpoint to not return an error.
+    ///
+    /// See [`OsuBuilder::with_authorization`](crate::OsuBuilder::with_authorization).
+    #[inline]
+    pub fn own_favourites(&self) -> GetOwnFavourites<'_> {
+        GetOwnFavourites::new(self)
+    }
+
     /// Get a [`Rankings`](crate::model::ranking::Rankings) struct whose
     /// [`UserCompact`](crate::model::user::UserCompact)s are sorted
     /// by their pp, i.e. the current pp leaderboard.
@@ -344,7 +508,7 @@ impl Osu {
         GetRecentEvents::new(self, user_id.into())
     }
 
-    /// Get the replay of a score in form of a [`Replay`](osu_db::Replay).
+    /// Get the replay of a score in form of a [`Replay`](crate::model::replay::Replay).
     ///
     /// Note that the client has to be initialized through the OAuth process
     /// in order for this endpoint to not return an error.
@@ -367,6 +531,26 @@ impl Osu {
         GetReplayRaw::new(self, mode, score_id)
     }
 
+    /// Get the bytes of a replay of a score in form of a
+    /// [`ResponseStream`](crate::request::ResponseStream) instead of
+    /// buffering them entirely.
+    ///
+    /// Note that the client has to be initialized through the OAuth process
+    /// in order for this endpoint to not return an error.
+    ///
+    /// See [`OsuBuilder::with_authorization`](crate::OsuBuilder::with_authorization).
+    #[inline]
+    pub fn replay_stream(&self, mode: GameMode, score_id: u64) -> GetReplayStream<'_> {
+        GetReplayStream::new(self, mode, score_id)
+    }
+
+    /// Get the [`RoomLeaderboard`](crate::model::room::RoomLeaderboard) of a room
+    /// such as the daily challenge.
+    #[inline]
+    pub fn room_leaderboard(&self, room_id: u64) -> GetRoomLeaderboard<'_> {
+        GetRoomLeaderboard::new(self, room_id)
+    }
+
     /// Get a [`Score`](crate::model::score::Score) struct.
     ///
     /// The contained score will have the following options filled:
@@ -387,18 +571,76 @@ impl Osu {
         GetScoreRankings::new(self, mode)
     }
 
+    /// Search for [`UserCompact`](crate::model::user::UserCompact)s and
+    /// [`WikiPage`](crate::model::wiki::WikiPage)s matching the query.
+    #[inline]
+    pub fn search(&self, query: impl Into<String>) -> GetSearch<'_> {
+        GetSearch::new(self, query)
+    }
+
     /// Get [`SeasonalBackgrounds`](crate::model::seasonal_backgrounds::SeasonalBackgrounds).
     #[inline]
     pub fn seasonal_backgrounds(&self) -> GetSeasonalBackgrounds<'_> {
         GetSeasonalBackgrounds::new(self)
     }
 
+    /// Get the vec of [`Season`](crate::model::room::Season)s.
+    #[inline]
+    pub fn seasons(&self) -> GetSeasons<'_> {
+        GetSeasons::new(self)
+    }
+
     /// Get the vec of [`Spotlight`](crate::model::ranking::Spotlight).
     #[inline]
     pub fn spotlights(&self) -> GetSpotlights<'_> {
         GetSpotlights::new(self)
     }
 
+    /// Get a [`Team`](crate::model::team::Team).
+    #[inline]
+    pub fn team(&self, team_id: u32) -> GetTeam<'_> {
+        GetTeam::new(self, team_id)
+    }
+
+    /// Get the members of a team in form of a vec of
+    /// [`UserCompact`](crate::model::user::UserCompact)s.
+    #[inline]
+    pub fn team_members(&self, team_id: u32) -> GetTeamMembers<'_> {
+        GetTeamMembers::new(self, team_id)
+    }
+
+    /// Get a [`TeamRankings`](crate::model::ranking::TeamRankings) struct
+    /// containing a vec of [`TeamRanking`](crate::model::ranking::TeamRanking)s
+    /// which will be sorted by the team's total pp.
+    #[inline]
+    pub fn team_rankings(&self, mode: GameMode) -> GetTeamRankings<'_> {
+        GetTeamRankings::new(self, mode)
+    }
+
+    /// Unfavourite a [`Beatmapset`](crate::model::beatmap::Beatmapset)
+    /// for the authenticated user and get its updated `favourite_count`.
+    ///
+    /// Note that the client has to be initialized through the OAuth process
+    /// in order for this endpoint to not return an error.
+    ///
+    /// See [`OsuBuilder::with_authorization`](crate::OsuBuilder::with_authorization).
+    #[inline]
+    pub fn unfavourite_beatmapset(&self, mapset_id: u32) -> FavouriteBeatmapset<'_> {
+        FavouriteBeatmapset::new(self, mapset_id, false)
+    }
+
+    /// Stop watching the discussion of a
+    /// [`Beatmapset`](crate::model::beatmap::Beatmapset) for the authenticated user.
+    ///
+    /// Note that the client has to be initialized through the OAuth process
+    /// in order for this endpoint to not return an error.
+    ///
+    /// See [`OsuBuilder::with_authorization`](crate::OsuBuilder::with_authorization).
+    #[inline]
+    pub fn unwatch_beatmapset(&self, mapset_id: u32) -> WatchBeatmapset<'_> {
+        WatchBeatmapset::new(self, mapset_id, false)
+    }
+
     /// Get a [`User`](crate::model::user::User).
     ///
     /// The following options will be filled if the user specified them:
@@ -542,7 +784,20 @@ impl Osu {
         GetUsers::new(self, user_ids)
     }
 
-    /// Get a [`WikiPage`](crate::model::wiki::WikiPage) or image data.
+    /// Watch the discussion of a [`Beatmapset`](crate::model::beatmap::Beatmapset)
+    /// for the authenticated user, i.e. receive notifications about new posts.
+    ///
+    /// Note that the client has to be initialized through the OAuth process
+    /// in order for this endpoint to not return an error.
+    ///
+    /// See [`OsuBuilder::with_authorization`](crate::OsuBuilder::with_authorization).
+    #[inline]
+    pub fn watch_beatmapset(&self, mapset_id: u32) -> WatchBeatmapset<'_> {
+        WatchBeatmapset::new(self, mapset_id, true)
+    }
+
+    /// Get a [`WikiPage`](crate::model::wiki::WikiPage) or image data
+    /// wrapped in a [`WikiResponse`](crate::model::wiki::WikiResponse).
     ///
     /// `locale` adjusts the language, e.g. `en` for english, `de` for german, ...
     #[inline]
@@ -577,6 +832,36 @@ impl Osu {
         }
     }
 
+    /// Forget all checksums that [`Osu::beatmaps_by_checksums`] remembered
+    /// as not belonging to a map.
+    #[cfg(feature = "cache")]
+    #[inline]
+    pub fn clear_missing_checksums(&self) {
+        self.missing_checksums.clear();
+    }
+
+    #[cfg(feature = "cache")]
+    pub(crate) fn is_missing_checksum(&self, checksum: &str) -> bool {
+        self.missing_checksums
+            .remove_if(checksum, |_, at| at.elapsed() >= MISSING_CHECKSUM_TTL);
+
+        self.missing_checksums.contains_key(checksum)
+    }
+
+    #[cfg(feature = "cache")]
+    pub(crate) fn insert_missing_checksum(&self, checksum: String) {
+        if self.missing_checksums.len() >= MISSING_CHECKSUMS_CAP {
+            self.missing_checksums
+                .retain(|_, at| at.elapsed() < MISSING_CHECKSUM_TTL);
+
+            if self.missing_checksums.len() >= MISSING_CHECKSUMS_CAP {
+                return;
+            }
+        }
+
+        self.missing_checksums.insert(checksum, Instant::now());
+    }
+
     #[cfg(feature = "cache")]
     pub(crate) fn update_cache(&self, user_id: u32, username: &Username) {
         let mut name = username.to_owned();
@@ -591,6 +876,31 @@ impl Osu {
     pub(crate) async fn request_raw(&self, req: Request) -> OsuResult<Bytes> {
         self.inner.request_raw(req).await
     }
+
+    pub(crate) async fn request_raw_with_content_type(
+        &self,
+        req: Request,
+    ) -> OsuResult<(Option<HeaderValue>, Bytes)> {
+        self.inner.request_raw_with_content_type(req).await
+    }
+
+    pub(crate) async fn request_stream(&self, req: Request) -> OsuResult<HyperBody> {
+        self.inner.request_stream(req).await
+    }
+
+    /// The id of the authenticated user if it has been retrieved already
+    #[inline]
+    pub(crate) fn own_user_id(&self) -> Option<u32> {
+        match self.inner.own_user_id.load(Ordering::Relaxed) {
+            0 => None,
+            user_id => Some(user_id),
+        }
+    }
+
+    #[inline]
+    pub(crate) fn set_own_user_id(&self, user_id: u32) {
+        self.inner.own_user_id.store(user_id, Ordering::Relaxed);
+    }
 }
 
 impl Drop for Osu {
@@ -611,6 +921,8 @@ pub(crate) struct OsuRef {
     auth_kind: AuthorizationKind,
     token: RwLock<Token>,
     retries: usize,
+    /// Id of the authenticated user, `0` if not yet known
+    own_user_id: AtomicU32,
 }
 
 static MY_USER_AGENT: &str = concat!(
@@ -626,6 +938,9 @@ const X_API_VERSION: &str = "x-api-version";
 
 const API_VERSION: u32 = 20220705;
 
+/// Maximum amount of redirects that are followed for streamed responses
+const MAX_REDIRECTS: usize = 5;
+
 impl OsuRef {
     async fn request_token(&self) -> OsuResult<TokenResponse> {
         let mut body = Body::default();
@@ -686,6 +1001,71 @@ impl OsuRef {
         Ok(bytes)
     }
 
+    async fn request_raw_with_content_type(
+        &self,
+        req: Request,
+    ) -> OsuResult<(Option<HeaderValue>, Bytes)> {
+        let resp = self.raw(req).await?;
+        let content_type = resp.headers().get(CONTENT_TYPE).cloned();
+        let bytes = self.handle_status(resp).await?;
+
+        Ok((content_type, bytes))
+    }
+
+    /// Request the body without buffering it, following redirects if necessary.
+    ///
+    /// Only the initial request goes through the ratelimiter,
+    /// redirect targets such as the CDN are requested directly.
+    async fn request_stream(&self, req: Request) -> OsuResult<HyperBody> {
+        let mut resp = self.raw(req).await?;
+        let base = "https://osu.ppy.sh";
+        let mut url = Url::parse(base).map_err(|source| OsuError::Url {
+            source,
+            url: base.to_owned(),
+        })?;
+        let mut redirects = 0;
+
+        while resp.status().is_redirection() {
+            let location = match resp.headers().get(LOCATION) {
+                Some(location) => String::from_utf8_lossy(location.as_bytes()).into_owned(),
+                None => break,
+            };
+
+            if redirects == MAX_REDIRECTS {
+                return Err(OsuError::TooManyRedirects { url: location });
+            }
+
+            redirects += 1;
+
+            url = url.join(&location).map_err(|source| OsuError::Url {
+                source,
+                url: location,
+            })?;
+
+            debug!("Redirect: {}", url);
+
+            let req = HyperRequest::builder()
+                .method(Method::GET)
+                .uri(url.as_str())
+                .header(USER_AGENT, MY_USER_AGENT)
+                .body(BodyBytes::default())?;
+
+            resp = self.send(req).await?;
+        }
+
+        let status = resp.status();
+
+        if status == StatusCode::OK {
+            return Ok(resp.into_body());
+        }
+
+        let bytes = hyper::body::to_bytes(resp.into_body())
+            .await
+            .map_err(|source| OsuError::ChunkingResponse { source })?;
+
+        Err(status_error(status, bytes))
+    }
+
     async fn raw(&self, req: Request) -> OsuResult<Response<HyperBody>> {
         let Request {
             query,
@@ -728,6 +1108,11 @@ impl OsuRef {
     async fn send_request(&self, req: HyperRequest<BodyBytes>) -> OsuResult<Response<HyperBody>> {
         self.ratelimiter.acquire_one().await;
 
+        self.send(req).await
+    }
+
+    /// Send the request without waiting for the ratelimiter
+    async fn send(&self, req: HyperRequest<BodyBytes>) -> OsuResult<Response<HyperBody>> {
         let mut attempt = 0;
 
         loop {
@@ -752,34 +1137,39 @@ impl OsuRef {
             .map_err(|source| OsuError::ChunkingResponse { source })?;
 
         match status {
-            StatusCode::OK => return Ok(bytes),
-            StatusCode::NOT_FOUND => return Err(OsuError::NotFound),
-            StatusCode::SERVICE_UNAVAILABLE => {
-                let body = String::from_utf8_lossy(&bytes).into_owned();
-
-                return Err(OsuError::ServiceUnavailable(body));
-            }
-            StatusCode::TOO_MANY_REQUESTS => warn!("Got a 429 response"),
-            _ => {}
+            StatusCode::OK | StatusCode::NO_CONTENT => Ok(bytes),
+            _ => Err(status_error(status, bytes)),
         }
+    }
+}
 
-        let body = String::from_utf8_lossy(&bytes).into_owned();
+/// Create the error for a response whose status is not `200 OK`
+fn status_error(status: StatusCode, bytes: Bytes) -> OsuError {
+    match status {
+        StatusCode::NOT_FOUND => return OsuError::NotFound,
+        StatusCode::SERVICE_UNAVAILABLE => {
+            let body = String::from_utf8_lossy(&bytes).into_owned();
 
-        let source = match serde_json::from_slice(&bytes) {
-            Ok(source) => source,
-            Err(source) => return Err(OsuError::Parsing { body, source }),
-        };
+            return OsuError::ServiceUnavailable(body);
+        }
+        StatusCode::TOO_MANY_REQUESTS => warn!("Got a 429 response"),
+        _ => {}
+    }
+
+    let body = String::from_utf8_lossy(&bytes).into_owned();
 
-        Err(OsuError::Response {
+    match serde_json::from_slice(&bytes) {
+        Ok(source) => OsuError::Response {
             body,
             source,
             status,
-        })
+        },
+        Err(source) => OsuError::Parsing { body, source },
     }
 }
 
 #[inline]
-fn parse_bytes<T: DeserializeOwned>(bytes: Bytes) -> OsuResult<T> {
+pub(crate) fn parse_bytes<T: DeserializeOwned>(bytes: Bytes) -> OsuResult<T> {
     serde_json::from_slice(&bytes).map_err(|source| {
         let body = String::from_utf8_lossy(&bytes).into_owned();
 
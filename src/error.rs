@@ -42,13 +42,13 @@ pub enum OsuError {
     NotFound,
     /// Attempted to make request without valid token
     NoToken,
-    #[cfg(feature = "replay")]
-    /// There was an error while trying to use osu-db
-    OsuDbError { source: osu_db::Error },
     /// Failed to deserialize response
     Parsing { body: String, source: SerdeError },
     /// Failed to parse a value
     ParsingValue { source: ParsingError },
+    #[cfg(feature = "replay")]
+    /// Failed to parse or create a replay
+    Replay { source: ReplayError },
     /// Failed to send request
     Request { source: HyperError },
     /// Timeout while requesting from API
@@ -61,6 +61,11 @@ pub enum OsuError {
     },
     /// Temporal (?) downtime of the osu API
     ServiceUnavailable(String),
+    /// Stopped following redirects because there were too many
+    TooManyRedirects {
+        /// URL of the redirect that was no longer followed
+        url: String,
+    },
     /// The client's authentication is not sufficient for the endpoint
     UnavailableEndpoint,
     /// Failed to update token
@@ -83,14 +88,15 @@ impl StdError for OsuError {
             Self::CreatingTokenHeader { source } => Some(source),
             Self::NotFound => None,
             Self::NoToken => None,
-            #[cfg(feature = "replay")]
-            Self::OsuDbError { source } => Some(source),
             Self::Parsing { source, .. } => Some(source),
             Self::ParsingValue { source } => Some(source),
+            #[cfg(feature = "replay")]
+            Self::Replay { source } => Some(source),
             Self::Request { source } => Some(source),
             Self::RequestTimeout => None,
             Self::Response { source, .. } => Some(source),
             Self::ServiceUnavailable(_) => None,
+            Self::TooManyRedirects { .. } => None,
             Self::UnavailableEndpoint => None,
             Self::UpdateToken { source } => Some(source),
             Self::Url { source, .. } => Some(source),
@@ -121,10 +127,10 @@ impl fmt::Display for OsuError {
                 Can not send requests until a new token has been acquired. \
                 This should only occur during an extended downtime of the osu!api.",
             ),
-            #[cfg(feature = "replay")]
-            Self::OsuDbError { .. } => f.write_str("osu-db error"),
             Self::Parsing { body, .. } => write!(f, "failed to deserialize response: {}", body),
             Self::ParsingValue { .. } => f.write_str("failed to parse value"),
+            #[cfg(feature = "replay")]
+            Self::Replay { .. } => f.write_str("failed to process replay"),
             Self::Request { .. } => f.write_str("failed to send request"),
             Self::RequestTimeout => f.write_str("osu!api did not respond in time"),
             Self::Response { status, .. } => write!(f, "response error, status {}", status),
@@ -133,6 +139,9 @@ impl fmt::Display for OsuError {
                 "osu!api may be temporarily unavailable (received 503): {}",
                 body
             ),
+            Self::TooManyRedirects { url } => {
+                write!(f, "too many redirects, stopped before `{}`", url)
+            }
             Self::UnavailableEndpoint => {
                 f.write_str("the endpoint is not available for the client's authorization level")
             }
@@ -143,9 +152,9 @@ impl fmt::Display for OsuError {
 }
 
 #[cfg(feature = "replay")]
-impl From<osu_db::Error> for OsuError {
-    fn from(e: osu_db::Error) -> Self {
-        Self::OsuDbError { source: e }
+impl From<ReplayError> for OsuError {
+    fn from(e: ReplayError) -> Self {
+        Self::Replay { source: e }
     }
 }
 
@@ -201,3 +210,97 @@ impl fmt::Display for ParsingError {
         }
     }
 }
+
+/// Failed to parse or create a [`Replay`](crate::model::replay::Replay)
+#[cfg(feature = "replay")]
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ReplayError {
+    /// Failed to compress data with LZMA
+    Compress(std::io::Error),
+    /// Failed to decompress LZMA data
+    Decompress(lzma_rs::error::Error),
+    /// Failed to parse a frame of the life bar or the replay data
+    Frame(String),
+    /// Failed to (de)serialize the additional data of the lazer client
+    LazerData(SerdeError),
+    /// Invalid byte for a [`GameMode`](crate::model::GameMode)
+    Mode(u8),
+    /// Invalid byte at the start of a string
+    StringPrefix(u8),
+    /// Timestamp out of range
+    Timestamp(i64),
+    /// A variable-length integer does not fit into a `usize`
+    Uleb128,
+    /// The data ended prematurely
+    UnexpectedEof,
+    /// A string was not valid UTF-8
+    Utf8,
+}
+
+#[cfg(feature = "replay")]
+impl StdError for ReplayError {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match self {
+            Self::Compress(source) => Some(source),
+            Self::Decompress(source) => Some(source),
+            Self::LazerData(source) => Some(source),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "replay")]
+impl fmt::Display for ReplayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Compress(_) => f.write_str("failed to compress data"),
+            Self::Decompress(_) => f.write_str("failed to decompress data"),
+            Self::Frame(s) => write!(f, "failed to parse frame `{}`", s),
+            Self::LazerData(_) => f.write_str("failed to (de)serialize lazer data"),
+            Self::Mode(n) => write!(f, "failed to parse {} into GameMode", n),
+            Self::StringPrefix(n) => write!(f, "invalid string prefix {}", n),
+            Self::Timestamp(n) => write!(f, "timestamp {} is out of range", n),
+            Self::Uleb128 => f.write_str("variable-length integer is too large"),
+            Self::UnexpectedEof => f.write_str("unexpected end of data"),
+            Self::Utf8 => f.write_str("invalid UTF-8 string"),
+        }
+    }
+}
+
+/// Failed to parse or create a [`CollectionDb`](crate::model::osu_db::CollectionDb)
+/// or [`OsuDb`](crate::model::osu_db::OsuDb)
+#[cfg(feature = "osu-db")]
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum OsuDbError {
+    /// Invalid byte for a [`GameMode`](crate::model::GameMode)
+    Mode(u8),
+    /// Invalid byte at the start of a string
+    StringPrefix(u8),
+    /// Timestamp out of range
+    Timestamp(i64),
+    /// A variable-length integer does not fit into a `usize`
+    Uleb128,
+    /// The data ended prematurely
+    UnexpectedEof,
+    /// A string was not valid UTF-8
+    Utf8,
+}
+
+#[cfg(feature = "osu-db")]
+impl StdError for OsuDbError {}
+
+#[cfg(feature = "osu-db")]
+impl fmt::Display for OsuDbError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Mode(n) => write!(f, "failed to parse {} into GameMode", n),
+            Self::StringPrefix(n) => write!(f, "invalid string prefix {}", n),
+            Self::Timestamp(n) => write!(f, "timestamp {} is out of range", n),
+            Self::Uleb128 => f.write_str("variable-length integer is too large"),
+            Self::UnexpectedEof => f.write_str("unexpected end of data"),
+            Self::Utf8 => f.write_str("invalid UTF-8 string"),
+        }
+    }
+}
@@ -26,27 +26,40 @@
 //! - `beatmaps/lookup`: A specific beatmap including its beatmapset
 //! - `beatmaps`: Up to 50 beatmaps at once including their beatmapsets.
 //! - `beatmaps/{map_id}/attributes`: The difficulty attributes of a beatmap
+//! - `beatmaps/packs`: List of beatmap packs of a specific type
+//! - `beatmaps/packs/{tag}`: A specific beatmap pack including its beatmapsets
 //! - `beatmaps/{map_id}/scores`: The global score leaderboard for a beatmap
 //! - `beatmaps/{map_id}/scores/users/{user_id}[/all]`: Get (all) top score(s) of a user on a beatmap. Defaults to the play with the __max score__, not pp
 //! - `beatmapsets/{mapset_id}`: The beatmapset including all of its difficulty beatmaps
+//! - `beatmapsets/{mapset_id}/download`: The `.osz` archive of a beatmapset, streamed in chunks
+//! - `beatmapsets/{mapset_id}/favourites`: Favourite or unfavourite a beatmapset (requires OAuth)
 //! - `beatmapsets/events`: Various events around a beatmapset such as status, genre, or language updates, kudosu transfers, or new issues
 //! - `beatmapsets/search`: Search for beatmapsets; the same search as on the osu! website
+//! - `beatmapsets/watches/{mapset_id}`: Watch or unwatch the discussion of a beatmapset (requires OAuth)
 //! - `comments`: Most recent comments and their replies up to two levels deep
+//! - `events`: Site-wide feed of recent events like achieved medals, ranks on beatmaps, or beatmapset status updates
 //! - `forums/topics/{topic_id}`: A forum topic and its posts
 //! - `matches`: List of currently open multiplayer lobbies
 //! - `matches/{match_id}`: More specific data about a specific multiplayer lobby including participating players and occured events
 //! - `me[/{mode}]`: Detailed info about the authenticated user [in the specified mode] (requires OAuth)
 //! - `news`: Recent news
-//! - `rankings/{mode}/{ranking_type}`: The global leaderboard of either performance points, ranked score, countries, or a spotlight
+//! - `rankings/{mode}/{ranking_type}`: The global leaderboard of either performance points, ranked score, countries, teams, or a spotlight
+//! - `rankings/kudosu`: The global leaderboard of kudosu
 //! - `users/{user_id}/{recent_activity}`: List of a user's recent events like achieved medals, ranks on a beatmaps, username changes, supporter status updates, beatmapset status updates, ...
+//! - `rooms`: The room of the current daily challenge
+//! - `rooms/{room_id}/leaderboard`: The leaderboard of a multiplayer room such as the daily challenge
 //! - `scores/{mode}/{score_id}`: A specific score including its beatmap, beatmapset, and user
+//! - `search`: Search for users and/or wiki pages
 //! - `seasonal-backgrounds`: List of seasonal backgrounds i.e. their URL and artists
+//! - `seasons`: List of ranked playlist seasons
 //! - `spotlights`: List of overviews of all spotlights
+//! - `teams/{team_id}`: A specific team
+//! - `teams/{team_id}/members`: List of a team's members
 //! - `users/{user_id}[/{mode}]`: Detailed info about a user [in the specified mode]
 //! - `users/{user_id}/{beatmapsets/{map_type}`: List of beatmapsets either created, favourited, or most played by the user
 //! - `users/{user_id}/kudosu`: A user's recent kudosu transfers
 //! - `users/{user_id}/scores/{score_type}`: Either top, recent, pinned, or global #1 scores of a user
-//! - `wiki/{locale}[/{path}]`: The general wiki page or a specific topic if the path is specified
+//! - `wiki/{locale}[/{path}]`: The general wiki page or a specific topic if the path is specified, or image data
 //!
 //! The api itself provides a bunch more endpoints which are not yet implemented because they're really niche and/or missing any documentation.
 //!
@@ -97,7 +110,7 @@
 //!         .unwrap_or_else(|why| panic!("Failed to search mapsets: {}", why));
 //!
 //!     // Get the german wiki page on hit objects
-//!     let wiki_page: WikiPage = osu.wiki("de")
+//!     let wiki_page: WikiResponse = osu.wiki("de")
 //!         .page("Hit_object")
 //!         .await
 //!         .unwrap_or_else(|why| panic!("Failed to get wiki page: {}", why));
@@ -112,6 +125,8 @@
 //! | `default` | Enable the `cache` feature |
 //! | `cache` | Cache username-user_id pairs so that usernames can be used on all user endpoints instead of only user ids | [dashmap](https://github.com/xacrimon/dashmap)
 //! | `metrics` | Provide a count of all request types the client makes with the function `Osu::metrics` returning a `prometheus::IntCounterVec` | [prometheus](https://github.com/tikv/rust-prometheus)
+//! | `osu-db` | Provide `CollectionDb` to read and write osu!stable's `collection.db` and the read-only `OsuDb` for `osu!.db`, both importing their beatmaps through the API |
+//! | `replay` | Enables the method `Osu::replay` to parse a replay, as well as `Replay` to read and write `.osr` files. Note that `Osu::replay_raw` is available without this feature | [lzma-rs](https://github.com/gendx/lzma-rs)
 //! | `rkyv` | Implement rkyv's `Archive`, `Deserialize`, and `Serialize` for most types, allowing for insanely fast (de)serializing. | [rkyv](https://github.com/rkyv/rkyv)
 //!
 
@@ -146,8 +161,9 @@ pub mod prelude {
         error::OsuError,
         model::{
             beatmap::*, comments::*, forum::*, kudosu::*, matches::*, news::*, ranking::*,
-            recent_event::*, score::*, seasonal_backgrounds::*, user::*, wiki::*, Cursor, GameMode,
-            GameMods, Grade,
+            recent_event::*, room::*, score::*, search::*, seasonal_backgrounds::*, user::*,
+            wiki::*, Cursor, GameMod, GameModIntermediate, GameMode, GameMods,
+            GameModsIntermediate, Grade,
         },
         request::UserId,
         Osu, OsuBuilder, OsuResult,
@@ -159,6 +175,12 @@ pub mod prelude {
     #[cfg(feature = "metrics")]
     pub use prometheus::IntCounterVec;
 
+    #[cfg(feature = "osu-db")]
+    pub use crate::model::osu_db::*;
+
+    #[cfg(feature = "replay")]
+    pub use crate::model::replay::*;
+
     #[cfg(feature = "rkyv")]
     pub use crate::model::rkyv;
 }
@@ -9,17 +9,28 @@ pub(crate) struct Metrics {
     pub(crate) beatmap_scores: IntCounter,
     pub(crate) beatmap_user_score: IntCounter,
     pub(crate) beatmap_difficulty_attributes: IntCounter,
+    pub(crate) beatmap_pack: IntCounter,
+    pub(crate) beatmap_packs: IntCounter,
     pub(crate) beatmaps: IntCounter,
     pub(crate) beatmapset: IntCounter,
+    pub(crate) beatmapset_download: IntCounter,
+    pub(crate) beatmapset_favourite: IntCounter,
     pub(crate) beatmapset_from_map_id: IntCounter,
     pub(crate) beatmapset_events: IntCounter,
     pub(crate) beatmapset_search: IntCounter,
+    pub(crate) beatmapset_watch: IntCounter,
 
     #[cfg(feature = "cache")]
     pub(crate) cache_size: IntCounter,
 
     pub(crate) comments: IntCounter,
 
+    pub(crate) events: IntCounter,
+
+    pub(crate) daily_challenge: IntCounter,
+    pub(crate) room_leaderboard: IntCounter,
+    pub(crate) seasons: IntCounter,
+
     pub(crate) forum_posts: IntCounter,
 
     pub(crate) osu_match: IntCounter,
@@ -29,17 +40,25 @@ pub(crate) struct Metrics {
 
     pub(crate) chart_rankings: IntCounter,
     pub(crate) country_rankings: IntCounter,
+    pub(crate) kudosu_rankings: IntCounter,
     pub(crate) performance_rankings: IntCounter,
     pub(crate) score_rankings: IntCounter,
+    pub(crate) team_rankings: IntCounter,
     pub(crate) spotlights: IntCounter,
 
+    pub(crate) search: IntCounter,
+
     pub(crate) seasonal_backgrounds: IntCounter,
 
     pub(crate) score: IntCounter,
 
+    pub(crate) team: IntCounter,
+    pub(crate) team_members: IntCounter,
+
     pub(crate) replay: IntCounter,
 
     pub(crate) own_data: IntCounter,
+    pub(crate) own_favourites: IntCounter,
     pub(crate) user: IntCounter,
     pub(crate) user_beatmapsets: IntCounter,
     pub(crate) recent_events: IntCounter,
@@ -66,17 +85,28 @@ impl Metrics {
             beatmap_user_score: counters.with_label_values(&["Beatmap user scores"]),
             beatmap_difficulty_attributes: counters
                 .with_label_values(&["Beatmap difficulty attributes"]),
+            beatmap_pack: counters.with_label_values(&["Beatmap pack"]),
+            beatmap_packs: counters.with_label_values(&["Beatmap packs"]),
             beatmaps: counters.with_label_values(&["Beatmaps"]),
             beatmapset: counters.with_label_values(&["Beatmapset"]),
+            beatmapset_download: counters.with_label_values(&["Beatmapset download"]),
+            beatmapset_favourite: counters.with_label_values(&["Beatmapset favourite"]),
             beatmapset_events: counters.with_label_values(&["Beatmapset events"]),
             beatmapset_from_map_id: counters.with_label_values(&["Beatmapset from Beatmap ID"]),
             beatmapset_search: counters.with_label_values(&["Beatmapset search"]),
+            beatmapset_watch: counters.with_label_values(&["Beatmapset watch"]),
 
             #[cfg(feature = "cache")]
             cache_size: counters.with_label_values(&["Cached Username-UserId pairs"]),
 
             comments: counters.with_label_values(&["Comments"]),
 
+            events: counters.with_label_values(&["Events"]),
+
+            daily_challenge: counters.with_label_values(&["Daily challenge"]),
+            room_leaderboard: counters.with_label_values(&["Room leaderboard"]),
+            seasons: counters.with_label_values(&["Seasons"]),
+
             forum_posts: counters.with_label_values(&["Forum posts"]),
 
             osu_match: counters.with_label_values(&["Matches"]),
@@ -86,17 +116,25 @@ impl Metrics {
 
             chart_rankings: counters.with_label_values(&["Chart rankings"]),
             country_rankings: counters.with_label_values(&["Country rankings"]),
+            kudosu_rankings: counters.with_label_values(&["Kudosu rankings"]),
             performance_rankings: counters.with_label_values(&["Performance rankings"]),
             score_rankings: counters.with_label_values(&["Score rankings"]),
+            team_rankings: counters.with_label_values(&["Team rankings"]),
             spotlights: counters.with_label_values(&["Spotlights"]),
 
+            search: counters.with_label_values(&["Search"]),
+
             seasonal_backgrounds: counters.with_label_values(&["Seasonal backgrounds"]),
 
             score: counters.with_label_values(&["Score"]),
 
+            team: counters.with_label_values(&["Team"]),
+            team_members: counters.with_label_values(&["Team members"]),
+
             replay: counters.with_label_values(&["Replay"]),
 
             own_data: counters.with_label_values(&["Own Data"]),
+            own_favourites: counters.with_label_values(&["Own favourites"]),
             user: counters.with_label_values(&["User"]),
             user_beatmapsets: counters.with_label_values(&["User mapsets"]),
             recent_events: counters.with_label_values(&["User events"]),
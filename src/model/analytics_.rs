@@ -0,0 +1,427 @@
+use super::{
+    game_mod::GameModsIntermediate,
+    score_::Score,
+    user_::{GradeCounts, UserStatistics, Username},
+};
+
+use std::{cmp::Reverse, ops::Range};
+
+/// The factor by which each score in a user's top plays is weighted less than the previous one
+pub const WEIGHT_FACTOR: f32 = 0.95;
+
+/// The maximum amount of bonus pp a user can get for their amount of ranked scores
+pub const MAX_BONUS_PP: f32 = 417.0 - 1.0 / 3.0;
+
+/// Calculate the bonus pp for the given amount of ranked scores.
+///
+/// Only the first 1000 scores contribute to the bonus pp.
+pub fn bonus_pp(ranked_scores: u32) -> f32 {
+    MAX_BONUS_PP * (1.0 - 0.995_f32.powi(ranked_scores.min(1000) as i32))
+}
+
+/// Calculate the bonus pp based on a user's grade counts.
+///
+/// Since scores with grades below A are not counted, this is a lower bound.
+pub fn bonus_pp_from_grades(grades: &GradeCounts) -> f32 {
+    let count = grades.ss + grades.ssh + grades.s + grades.sh + grades.a;
+
+    bonus_pp(count.max(0) as u32)
+}
+
+/// Aggregates over a list of scores, usually a user's top plays.
+///
+/// The scores are expected to be ordered by their pp in descending order,
+/// just like the API provides them through
+/// [`GetUserScores::best`](crate::request::GetUserScores::best).
+/// Scores with a [`weight`](Score::weight) use its pp value, others are
+/// weighted based on their position.
+#[derive(Copy, Clone, Debug)]
+pub struct ScoreAnalytics<'s> {
+    scores: &'s [Score],
+}
+
+impl<'s> ScoreAnalytics<'s> {
+    /// Create analytics for the given scores
+    #[inline]
+    pub fn new(scores: &'s [Score]) -> Self {
+        Self { scores }
+    }
+
+    /// The underlying scores
+    #[inline]
+    pub fn scores(&self) -> &'s [Score] {
+        self.scores
+    }
+
+    /// The weighted pp of each score alongside the score itself
+    pub fn weighted(&self) -> impl Iterator<Item = (&'s Score, f32)> + 's {
+        self.scores.iter().enumerate().map(|(i, score)| {
+            let weighted = match score.weight {
+                Some(ref weight) => weight.pp,
+                None => score.pp.unwrap_or(0.0) * WEIGHT_FACTOR.powi(i as i32),
+            };
+
+            (score, weighted)
+        })
+    }
+
+    /// Sum of the weighted pp of all scores
+    #[inline]
+    pub fn weighted_pp(&self) -> f32 {
+        self.weighted().map(|(_, pp)| pp).sum()
+    }
+
+    /// Estimate a user's bonus pp as the difference between their total pp
+    /// and the weighted pp of these scores.
+    ///
+    /// This is only accurate if the scores are the user's complete top plays.
+    /// The result is clamped between 0 and [`MAX_BONUS_PP`].
+    pub fn bonus_pp(&self, stats: &UserStatistics) -> f32 {
+        (stats.pp - self.weighted_pp()).clamp(0.0, MAX_BONUS_PP)
+    }
+
+    /// Arithmetic mean of the accuracy of all scores
+    pub fn average_accuracy(&self) -> Option<f32> {
+        let sum: f32 = self.scores.iter().map(|score| score.accuracy).sum();
+
+        (!self.scores.is_empty()).then(|| sum / self.scores.len() as f32)
+    }
+
+    /// Accuracy of all scores weighted the same way as their pp,
+    /// i.e. the way osu! calculates a user's accuracy.
+    pub fn weighted_accuracy(&self) -> Option<f32> {
+        let (sum, total) = (0..self.scores.len())
+            .map(|i| WEIGHT_FACTOR.powi(i as i32))
+            .zip(self.scores.iter())
+            .fold((0.0, 0.0), |(sum, total), (weight, score)| {
+                (sum + score.accuracy * weight, total + weight)
+            });
+
+        (total > 0.0).then(|| sum / total)
+    }
+
+    /// Arithmetic mean of the pp of all scores that have pp
+    pub fn average_pp(&self) -> Option<f32> {
+        let (sum, count) = self
+            .scores
+            .iter()
+            .filter_map(|score| score.pp)
+            .fold((0.0, 0), |(sum, count), pp| (sum + pp, count + 1));
+
+        (count > 0).then(|| sum / count as f32)
+    }
+
+    /// Range between the lowest and highest pp value
+    pub fn pp_range(&self) -> Option<Range<f32>> {
+        let mut pps = self.scores.iter().filter_map(|score| score.pp);
+        let first = pps.next()?;
+
+        let range = pps.fold(first..first, |range, pp| {
+            range.start.min(pp)..range.end.max(pp)
+        });
+
+        Some(range)
+    }
+
+    /// Scores grouped by their mod combination, regardless of the mods' order or settings.
+    ///
+    /// Ordered by the amount of scores in descending order.
+    pub fn mods(&self) -> Vec<Breakdown<GameModsIntermediate>> {
+        self.group_by(
+            |score| Some(score.mods.clone()),
+            |a, b| a.len() == b.len() && a.iter().all(|m| b.contains(&m.acronym)),
+        )
+    }
+
+    /// Scores grouped by the name of the beatmapset's creator.
+    ///
+    /// Scores without `mapset` are skipped.
+    /// Ordered by the amount of scores in descending order.
+    pub fn mappers(&self) -> Vec<Breakdown<Username>> {
+        self.group_by(
+            |score| {
+                score
+                    .mapset
+                    .as_ref()
+                    .map(|mapset| mapset.creator_name.clone())
+            },
+            |a, b| a.eq_ignore_ascii_case(b),
+        )
+    }
+
+    /// Scores grouped by the drain length of their beatmap into ranges of `bucket_secs` seconds.
+    ///
+    /// Scores without `map` are skipped.
+    /// Ordered by the range in ascending order.
+    pub fn lengths(&self, bucket_secs: u32) -> Vec<Breakdown<Range<u32>>> {
+        let bucket_secs = bucket_secs.max(1);
+
+        let mut breakdowns = self.group_by(
+            |score| {
+                let start = score.map.as_ref()?.seconds_drain / bucket_secs * bucket_secs;
+
+                Some(start..start + bucket_secs)
+            },
+            PartialEq::eq,
+        );
+
+        breakdowns.sort_unstable_by_key(|breakdown| breakdown.key.start);
+
+        breakdowns
+    }
+
+    fn group_by<K, F, E>(&self, key: F, eq: E) -> Vec<Breakdown<K>>
+    where
+        F: Fn(&Score) -> Option<K>,
+        E: Fn(&K, &K) -> bool,
+    {
+        let mut breakdowns: Vec<Breakdown<K>> = Vec::new();
+
+        for (score, weighted) in self.weighted() {
+            let key = match key(score) {
+                Some(key) => key,
+                None => continue,
+            };
+
+            let breakdown = match breakdowns.iter_mut().position(|b| eq(&b.key, &key)) {
+                Some(idx) => &mut breakdowns[idx],
+                None => {
+                    breakdowns.push(Breakdown::new(key));
+                    breakdowns.last_mut().unwrap()
+                }
+            };
+
+            breakdown.count += 1;
+
+            if let Some(pp) = score.pp {
+                breakdown.pp += pp;
+                breakdown.pp_count += 1;
+            }
+
+            breakdown.weighted_pp += weighted;
+            breakdown.accuracy_sum += score.accuracy;
+        }
+
+        breakdowns.sort_by_key(|breakdown| Reverse(breakdown.count));
+
+        breakdowns
+    }
+}
+
+/// Aggregate of all scores that share the same key
+#[derive(Clone, Debug, PartialEq)]
+pub struct Breakdown<K> {
+    /// The value that the scores have in common
+    pub key: K,
+    /// Amount of scores
+    pub count: usize,
+    /// Sum of the scores' unweighted pp
+    pub pp: f32,
+    /// Sum of the scores' weighted pp
+    pub weighted_pp: f32,
+    accuracy_sum: f32,
+    pp_count: usize,
+}
+
+impl<K> Breakdown<K> {
+    #[inline]
+    fn new(key: K) -> Self {
+        Self {
+            key,
+            count: 0,
+            pp: 0.0,
+            weighted_pp: 0.0,
+            accuracy_sum: 0.0,
+            pp_count: 0,
+        }
+    }
+
+    /// Arithmetic mean of the scores' accuracy
+    #[inline]
+    pub fn average_accuracy(&self) -> f32 {
+        self.accuracy_sum / self.count as f32
+    }
+
+    /// Arithmetic mean of the unweighted pp of the scores that have pp
+    #[inline]
+    pub fn average_pp(&self) -> f32 {
+        if self.pp_count == 0 {
+            return 0.0;
+        }
+
+        self.pp / self.pp_count as f32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{
+        beatmap::{BeatmapsetCompact, BeatmapsetCovers, RankStatus},
+        score_::{LazerScoreStatistics, ScoreWeight},
+        test_util::{assert_approx, map},
+        GameMode, Grade,
+    };
+    use std::str::FromStr;
+    use time::OffsetDateTime;
+
+    fn score(pp: Option<f32>, accuracy: f32, mods: &str, mapper: &str, drain: u32) -> Score {
+        let mut map = map(GameMode::Osu);
+        map.max_combo = Some(700);
+        map.seconds_drain = drain;
+        map.seconds_total = drain + 10;
+
+        let mapset = BeatmapsetCompact {
+            artist: String::new(),
+            artist_unicode: None,
+            covers: BeatmapsetCovers::new(1),
+            creator_name: mapper.into(),
+            creator_id: 2,
+            favourite_count: 0,
+            genre: None,
+            hype: None,
+            language: None,
+            mapset_id: 1,
+            nsfw: false,
+            playcount: 0,
+            preview_url: String::new(),
+            source: String::new(),
+            status: RankStatus::Ranked,
+            title: String::new(),
+            title_unicode: None,
+            video: false,
+        };
+
+        Score {
+            accuracy,
+            ended_at: OffsetDateTime::UNIX_EPOCH,
+            grade: Grade::A,
+            max_combo: 700,
+            map: Some(map),
+            mapset: Some(mapset),
+            mode: GameMode::Osu,
+            mods: GameModsIntermediate::from_str(mods).unwrap(),
+            perfect: false,
+            pp,
+            rank_country: None,
+            rank_global: None,
+            replay: None,
+            maximum_statistics: None,
+            score: 1_000_000,
+            score_id: None,
+            lazer_statistics: LazerScoreStatistics::default(),
+            user: None,
+            user_id: 2,
+            weight: None,
+        }
+    }
+
+    fn scores() -> Vec<Score> {
+        let mut weighted = score(Some(80.0), 90.0, "HR", "Sotarks", 200);
+        weighted.weight = Some(ScoreWeight {
+            percentage: 50.0,
+            pp: 40.0,
+        });
+
+        vec![
+            score(Some(100.0), 99.0, "HDDT", "Sotarks", 95),
+            score(Some(90.0), 95.0, "DTHD", "sotarks", 130),
+            weighted,
+            score(None, 80.0, "", "Monstrata", 100),
+        ]
+    }
+
+    #[test]
+    fn bonus_pp_bounds() {
+        assert!(bonus_pp(0).abs() < f32::EPSILON);
+        assert!((bonus_pp(1000) - bonus_pp(5000)).abs() < f32::EPSILON);
+        assert!(bonus_pp(1000) < MAX_BONUS_PP);
+        assert!(bonus_pp(1000) > 413.0);
+    }
+
+    #[test]
+    fn bonus_pp_grades() {
+        let grades = GradeCounts {
+            ss: 10,
+            ssh: 5,
+            s: 50,
+            sh: 20,
+            a: 15,
+        };
+
+        assert!((bonus_pp_from_grades(&grades) - bonus_pp(100)).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn empty_scores() {
+        let analytics = ScoreAnalytics::new(&[]);
+
+        assert!(analytics.weighted_pp().abs() < f32::EPSILON);
+        assert!(analytics.average_accuracy().is_none());
+        assert!(analytics.weighted_accuracy().is_none());
+        assert!(analytics.average_pp().is_none());
+        assert!(analytics.pp_range().is_none());
+        assert!(analytics.mods().is_empty());
+    }
+
+    #[test]
+    fn weighted_pp() {
+        let scores = scores();
+        let analytics = ScoreAnalytics::new(&scores);
+        let weighted: Vec<_> = analytics.weighted().map(|(_, pp)| pp).collect();
+
+        assert_eq!(weighted.len(), 4);
+        assert_approx(weighted[0], 100.0);
+        assert_approx(weighted[1], 90.0 * 0.95);
+        assert_approx(weighted[2], 40.0);
+        assert_approx(weighted[3], 0.0);
+        assert_approx(analytics.weighted_pp(), 225.5);
+    }
+
+    #[test]
+    fn accuracy_and_pp() {
+        let scores = scores();
+        let analytics = ScoreAnalytics::new(&scores);
+
+        let weights = [1.0, 0.95, 0.9025, 0.857_375];
+        let expected =
+            (99.0 * weights[0] + 95.0 * weights[1] + 90.0 * weights[2] + 80.0 * weights[3])
+                / weights.iter().sum::<f32>();
+
+        assert_approx(analytics.weighted_accuracy().unwrap(), expected);
+        assert_approx(analytics.average_accuracy().unwrap(), 91.0);
+        assert_approx(analytics.average_pp().unwrap(), 90.0);
+        assert_eq!(analytics.pp_range(), Some(80.0..100.0));
+    }
+
+    #[test]
+    fn breakdowns() {
+        let scores = scores();
+        let analytics = ScoreAnalytics::new(&scores);
+
+        let mods = analytics.mods();
+        assert_eq!(mods.len(), 3);
+        assert_eq!(mods[0].count, 2);
+        assert!(mods[0].key.contains("HD") && mods[0].key.contains("DT"));
+        assert_approx(mods[0].pp, 190.0);
+        assert_approx(mods[0].weighted_pp, 185.5);
+        assert_approx(mods[0].average_accuracy(), 97.0);
+
+        let mappers = analytics.mappers();
+        assert_eq!(mappers.len(), 2);
+        assert_eq!(mappers[0].key.as_str(), "Sotarks");
+        assert_eq!(mappers[0].count, 3);
+        assert_approx(mappers[0].average_pp(), 90.0);
+        assert_eq!(mappers[1].count, 1);
+        assert_approx(mappers[1].average_pp(), 0.0);
+
+        let lengths = analytics.lengths(60);
+        let keys: Vec<_> = lengths
+            .iter()
+            .map(|breakdown| breakdown.key.clone())
+            .collect();
+        assert_eq!(keys, vec![60..120, 120..180, 180..240]);
+        assert_eq!(lengths[0].count, 2);
+        assert_approx(lengths[0].average_pp(), 100.0);
+    }
+}
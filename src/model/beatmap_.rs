@@ -1,8 +1,11 @@
-use super::{serde_, user_::UserCompact, Cursor, GameMode};
+use super::{
+    beatmap_attributes_::BeatmapAttributes, serde_, user_::UserCompact, Cursor, GameMode, GameMods,
+    GameModsIntermediate, Grade,
+};
 use crate::{
     error::ParsingError,
     prelude::{CountryCode, OsuError, Username},
-    request::{GetBeatmapDifficultyAttributes, GetUser},
+    request::{GetBeatmapDifficultyAttributes, GetBeatmapsetDownload, GetUser},
     Osu, OsuResult,
 };
 
@@ -94,6 +97,33 @@ impl Beatmap {
     pub fn difficulty_attributes<'o>(&self, osu: &'o Osu) -> GetBeatmapDifficultyAttributes<'o> {
         GetBeatmapDifficultyAttributes::new(osu, self.map_id)
     }
+
+    /// Calculate the [`BeatmapAttributes`] after applying the given mods.
+    ///
+    /// Takes `HR`, `EZ`, `DT`, `NC`, `HT`, and `DC` into account.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use rosu_v2::model::{beatmap::Beatmap, GameMods};
+    /// # fn example(map: Beatmap) {
+    /// let attrs = map.attributes_with_mods(GameMods::HardRock | GameMods::DoubleTime);
+    ///
+    /// println!("AR{} OD{} {}bpm", attrs.ar, attrs.od, attrs.bpm);
+    /// # }
+    /// ```
+    #[inline]
+    pub fn attributes_with_mods(&self, mods: GameMods) -> BeatmapAttributes {
+        BeatmapAttributes::new(self, &mods.into())
+    }
+
+    /// Calculate the [`BeatmapAttributes`] after applying the given lazer mods.
+    ///
+    /// In addition to [`Beatmap::attributes_with_mods`], this respects custom
+    /// rates of rate changing mods as well as the settings of `DA`.
+    #[inline]
+    pub fn attributes_with_lazer_mods(&self, mods: &GameModsIntermediate) -> BeatmapAttributes {
+        BeatmapAttributes::new(self, mods)
+    }
 }
 
 impl PartialEq for Beatmap {
@@ -171,6 +201,11 @@ pub(crate) struct BeatmapDifficultyAttributesWrapper {
     pub attributes: BeatmapDifficultyAttributes,
 }
 
+#[derive(Deserialize)]
+pub(crate) struct BeatmapsetFavouriteCount {
+    pub favourite_count: u32,
+}
+
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 #[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
 pub struct BeatmapDifficultyAttributes {
@@ -212,6 +247,149 @@ pub enum GameModeAttributes {
     },
 }
 
+/// A bundle of beatmapsets which can be downloaded all at once.
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize)]
+#[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
+pub struct BeatmapPack {
+    /// Name of the user who put the pack together
+    pub author: String,
+    /// When the pack was released
+    #[serde(with = "serde_::datetime")]
+    #[cfg_attr(feature = "rkyv", with(super::rkyv_impls::DateTimeWrapper))]
+    pub date: OffsetDateTime,
+    /// Only available when requesting a specific pack
+    #[serde(
+        default,
+        rename = "beatmapsets",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub mapsets: Option<Vec<BeatmapsetCompact>>,
+    /// Only available for some pack types
+    #[serde(
+        default,
+        rename = "ruleset_id",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub mode: Option<GameMode>,
+    /// Name of the pack, e.g. `Beatmap Pack #1337`
+    pub name: String,
+    /// Whether difficulty reduction mods may be used to clear the pack
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub no_diff_reduction: Option<bool>,
+    /// Unique identifier of the pack, e.g. `S1337`, used to request a specific pack
+    pub tag: String,
+    /// Download URL of the pack
+    pub url: String,
+    /// How far the authenticated user is through the pack.
+    /// Only available when requesting a specific pack with OAuth.
+    #[serde(
+        default,
+        rename = "user_completion_data",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub user_completion: Option<BeatmapPackUserCompletion>,
+}
+
+impl BeatmapPack {
+    /// Returns the amount of mapsets in the pack the authenticated user has completed.
+    ///
+    /// Only available when requesting a specific pack with OAuth.
+    #[inline]
+    pub fn completed_count(&self) -> Option<usize> {
+        self.user_completion
+            .as_ref()
+            .map(|completion| completion.mapset_ids.len())
+    }
+}
+
+/// Completion status of a [`BeatmapPack`] for the authenticated user.
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize)]
+#[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
+pub struct BeatmapPackUserCompletion {
+    /// IDs of mapsets in the pack that the user has completed
+    #[serde(rename = "beatmapset_ids")]
+    pub mapset_ids: Vec<u32>,
+    /// Whether the user completed the entire pack
+    pub completed: bool,
+}
+
+/// A page of [`BeatmapPack`]s
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
+pub struct BeatmapPacks {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg(not(feature = "rkyv"))]
+    pub(crate) cursor: Option<Cursor>,
+    /// The packs of the page, newest first
+    #[serde(rename = "beatmap_packs")]
+    pub packs: Vec<BeatmapPack>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) pack_type: Option<BeatmapPackType>,
+}
+
+impl BeatmapPacks {
+    /// Returns whether there is a next page of beatmap packs,
+    /// retrievable via [`get_next`](BeatmapPacks::get_next).
+    #[inline]
+    #[cfg(not(feature = "rkyv"))]
+    pub fn has_more(&self) -> bool {
+        self.cursor.is_some()
+    }
+
+    /// If [`has_more`](BeatmapPacks::has_more) is true, the API can provide the next set of packs and this method will request them.
+    /// Otherwise, this method returns `None`.
+    #[inline]
+    #[cfg(not(feature = "rkyv"))]
+    pub async fn get_next(&self, osu: &Osu) -> Option<OsuResult<BeatmapPacks>> {
+        let cursor = self.cursor.clone()?;
+        let mut fut = osu.beatmap_packs().cursor(cursor);
+
+        if let Some(pack_type) = self.pack_type {
+            fut = fut.pack_type(pack_type);
+        }
+
+        Some(fut.await)
+    }
+}
+
+/// The different kinds of [`BeatmapPack`]s
+#[derive(Copy, Clone, Debug, Deserialize, PartialEq, Eq, Serialize)]
+#[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
+#[serde(rename_all = "snake_case")]
+pub enum BeatmapPackType {
+    Standard,
+    /// Featured artist packs
+    Featured,
+    Tournament,
+    Loved,
+    /// Spotlight packs
+    Chart,
+    Theme,
+}
+
+impl Default for BeatmapPackType {
+    #[inline]
+    fn default() -> Self {
+        Self::Standard
+    }
+}
+
+impl Display for BeatmapPackType {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let pack_type = match self {
+            Self::Standard => "standard",
+            Self::Featured => "featured",
+            Self::Tournament => "tournament",
+            Self::Loved => "loved",
+            Self::Chart => "chart",
+            Self::Theme => "theme",
+        };
+
+        f.write_str(pack_type)
+    }
+}
+
 /// Represents a beatmapset. This extends [`BeatmapsetCompact`] with additional attributes.
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[cfg_attr(
@@ -425,6 +603,7 @@ fn deser_mapset_user<'de, D: Deserializer<'de>>(d: D) -> Result<Option<UserCompa
                 is_moderator: None,
                 is_nat: None,
                 is_silenced: None,
+                kudosu: None,
                 loved_mapset_count: None,
                 medals: None,
                 monthly_playcounts: None,
@@ -438,6 +617,7 @@ fn deser_mapset_user<'de, D: Deserializer<'de>>(d: D) -> Result<Option<UserCompa
                 scores_recent_count: None,
                 statistics: None,
                 support_level: None,
+                team: None,
                 pending_mapset_count: None,
             }))
         }
@@ -466,6 +646,18 @@ impl Beatmapset {
     pub fn get_creator<'o>(&self, osu: &'o Osu) -> GetUser<'o> {
         osu.user(self.creator_id)
     }
+
+    /// Download the `.osz` archive of this mapset.
+    #[inline]
+    pub fn download<'o>(&self, osu: &'o Osu) -> GetBeatmapsetDownload<'o> {
+        osu.beatmapset_download(self.mapset_id)
+    }
+
+    /// Full URL of the audio preview, i.e. `https://b.ppy.sh/preview/{mapset_id}.mp3`
+    #[inline]
+    pub fn preview_audio_url(&self) -> String {
+        preview_audio_url(self.mapset_id)
+    }
 }
 
 impl PartialEq for Beatmapset {
@@ -604,6 +796,18 @@ impl BeatmapsetCompact {
     pub fn get_creator<'o>(&self, osu: &'o Osu) -> GetUser<'o> {
         osu.user(self.creator_id)
     }
+
+    /// Download the `.osz` archive of this mapset.
+    #[inline]
+    pub fn download<'o>(&self, osu: &'o Osu) -> GetBeatmapsetDownload<'o> {
+        osu.beatmapset_download(self.mapset_id)
+    }
+
+    /// Full URL of the audio preview, i.e. `https://b.ppy.sh/preview/{mapset_id}.mp3`
+    #[inline]
+    pub fn preview_audio_url(&self) -> String {
+        preview_audio_url(self.mapset_id)
+    }
 }
 
 impl From<Beatmapset> for BeatmapsetCompact {
@@ -658,6 +862,80 @@ pub struct BeatmapsetCovers {
     pub slim_cover_2x: String,
 }
 
+impl BeatmapsetCovers {
+    /// Create the URLs of all cover sizes for a mapset.
+    ///
+    /// Useful if only the mapset id is known, e.g. from a local `osu!.db`.
+    pub fn new(mapset_id: u32) -> Self {
+        Self {
+            cover: CoverSize::Cover.url(mapset_id),
+            cover_2x: CoverSize::Cover2x.url(mapset_id),
+            card: CoverSize::Card.url(mapset_id),
+            card_2x: CoverSize::Card2x.url(mapset_id),
+            list: CoverSize::List.url(mapset_id),
+            list_2x: CoverSize::List2x.url(mapset_id),
+            slim_cover: CoverSize::SlimCover.url(mapset_id),
+            slim_cover_2x: CoverSize::SlimCover2x.url(mapset_id),
+        }
+    }
+
+    /// Return the URL of the given size
+    #[inline]
+    pub fn get(&self, size: CoverSize) -> &str {
+        match size {
+            CoverSize::Cover => &self.cover,
+            CoverSize::Cover2x => &self.cover_2x,
+            CoverSize::Card => &self.card,
+            CoverSize::Card2x => &self.card_2x,
+            CoverSize::List => &self.list,
+            CoverSize::List2x => &self.list_2x,
+            CoverSize::SlimCover => &self.slim_cover,
+            CoverSize::SlimCover2x => &self.slim_cover_2x,
+        }
+    }
+}
+
+/// The sizes of a [`BeatmapsetCovers`]
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum CoverSize {
+    Cover,
+    Cover2x,
+    Card,
+    Card2x,
+    List,
+    List2x,
+    SlimCover,
+    SlimCover2x,
+}
+
+impl CoverSize {
+    /// Full URL of this size for a mapset,
+    /// i.e. `https://assets.ppy.sh/beatmaps/{mapset_id}/covers/{size}.jpg`
+    #[inline]
+    pub fn url(self, mapset_id: u32) -> String {
+        format!(
+            "https://assets.ppy.sh/beatmaps/{}/covers/{}.jpg",
+            mapset_id,
+            self.as_str()
+        )
+    }
+
+    /// The name of the size as used in URLs, e.g. `card@2x`
+    #[inline]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Cover => "cover",
+            Self::Cover2x => "cover@2x",
+            Self::Card => "card",
+            Self::Card2x => "card@2x",
+            Self::List => "list",
+            Self::List2x => "list@2x",
+            Self::SlimCover => "slimcover",
+            Self::SlimCover2x => "slimcover@2x",
+        }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
 pub struct BeatmapsetDiscussion {
@@ -877,6 +1155,76 @@ pub enum BeatmapsetEvent {
     },
 }
 
+impl BeatmapsetEvent {
+    /// Returns the type of the event
+    pub fn event_type(&self) -> BeatmapsetEventType {
+        match self {
+            Self::Disqualify { .. } => BeatmapsetEventType::Disqualify,
+            Self::GenreEdit { .. } => BeatmapsetEventType::GenreEdit,
+            Self::IssueReopen { .. } => BeatmapsetEventType::IssueReopen,
+            Self::IssueResolve { .. } => BeatmapsetEventType::IssueResolve,
+            Self::KudosuDeny { .. } => BeatmapsetEventType::KudosuDeny,
+            Self::KudosuGain { .. } => BeatmapsetEventType::KudosuGain,
+            Self::KudosuLost { .. } => BeatmapsetEventType::KudosuLost,
+            Self::LanguageEdit { .. } => BeatmapsetEventType::LanguageEdit,
+            Self::Love { .. } => BeatmapsetEventType::Love,
+            Self::Nominate { .. } => BeatmapsetEventType::Nominate,
+            Self::NsfwToggle { .. } => BeatmapsetEventType::NsfwToggle,
+            Self::OwnerChange { .. } => BeatmapsetEventType::OwnerChange,
+            Self::Rank { .. } => BeatmapsetEventType::Rank,
+            Self::Qualify { .. } => BeatmapsetEventType::Qualify,
+        }
+    }
+}
+
+/// The type of a [`BeatmapsetEvent`], used to filter
+/// [`GetBeatmapsetEvents`](crate::request::GetBeatmapsetEvents).
+#[derive(Copy, Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum BeatmapsetEventType {
+    Disqualify,
+    GenreEdit,
+    IssueReopen,
+    IssueResolve,
+    KudosuDeny,
+    KudosuGain,
+    KudosuLost,
+    LanguageEdit,
+    Love,
+    Nominate,
+    NsfwToggle,
+    #[serde(rename = "beatmap_owner_change")]
+    OwnerChange,
+    Rank,
+    Qualify,
+}
+
+impl Display for BeatmapsetEventType {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let event_type = match self {
+            Self::Disqualify => "disqualify",
+            Self::GenreEdit => "genre_edit",
+            Self::IssueReopen => "issue_reopen",
+            Self::IssueResolve => "issue_resolve",
+            Self::KudosuDeny => "kudosu_deny",
+            Self::KudosuGain => "kudosu_gain",
+            Self::KudosuLost => "kudosu_lost",
+            Self::LanguageEdit => "language_edit",
+            Self::Love => "love",
+            Self::Nominate => "nominate",
+            Self::NsfwToggle => "nsfw_toggle",
+            Self::OwnerChange => "beatmap_owner_change",
+            Self::Rank => "rank",
+            Self::Qualify => "qualify",
+        };
+
+        f.write_str(event_type)
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 #[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
 pub struct BeatmapsetEvents {
@@ -1046,9 +1394,23 @@ pub(crate) struct BeatmapsetSearchParameters {
     pub(crate) video: bool,
     pub(crate) storyboard: bool,
     pub(crate) nsfw: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) played: Option<bool>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub(crate) ranks: Vec<Grade>,
+    #[serde(skip_serializing_if = "is_false")]
+    pub(crate) recommended: bool,
+    #[serde(skip_serializing_if = "is_false")]
+    pub(crate) converts: bool,
+    #[serde(skip_serializing_if = "is_false")]
+    pub(crate) follows: bool,
+    #[serde(skip_serializing_if = "is_false")]
+    pub(crate) spotlights: bool,
+    #[serde(skip_serializing_if = "is_false")]
+    pub(crate) featured_artists: bool,
     #[serde(rename(serialize = "_sort"))]
-    sort: BeatmapsetSearchSort,
-    descending: bool,
+    pub(crate) sort: BeatmapsetSearchSort,
+    pub(crate) descending: bool,
 }
 
 impl Default for BeatmapsetSearchParameters {
@@ -1063,12 +1425,24 @@ impl Default for BeatmapsetSearchParameters {
             video: false,
             storyboard: false,
             nsfw: true,
+            played: None,
+            ranks: Vec::new(),
+            recommended: false,
+            converts: false,
+            follows: false,
+            spotlights: false,
+            featured_artists: false,
             sort: BeatmapsetSearchSort::default(),
             descending: true,
         }
     }
 }
 
+#[inline]
+fn is_false(b: &bool) -> bool {
+    !*b
+}
+
 struct BeatmapsetSearchParametersVisitor;
 
 impl<'de> Visitor<'de> for BeatmapsetSearchParametersVisitor {
@@ -1089,6 +1463,13 @@ impl<'de> Visitor<'de> for BeatmapsetSearchParametersVisitor {
         let mut video = None;
         let mut storyboard = None;
         let mut nsfw = None;
+        let mut played = None;
+        let mut ranks = None;
+        let mut recommended = None;
+        let mut converts = None;
+        let mut follows = None;
+        let mut spotlights = None;
+        let mut featured_artists = None;
         let mut sort = None;
         let mut descending = None;
 
@@ -1111,6 +1492,13 @@ impl<'de> Visitor<'de> for BeatmapsetSearchParametersVisitor {
                 "video" => video = Some(map.next_value()?),
                 "storyboard" => storyboard = Some(map.next_value()?),
                 "nsfw" => nsfw = Some(map.next_value()?),
+                "played" => played = map.next_value()?,
+                "ranks" => ranks = Some(map.next_value()?),
+                "recommended" => recommended = Some(map.next_value()?),
+                "converts" => converts = Some(map.next_value()?),
+                "follows" => follows = Some(map.next_value()?),
+                "spotlights" => spotlights = Some(map.next_value()?),
+                "featured_artists" => featured_artists = Some(map.next_value()?),
                 "_sort" => sort = Some(map.next_value()?),
                 "descending" => descending = Some(map.next_value()?),
                 _ => {
@@ -1138,6 +1526,13 @@ impl<'de> Visitor<'de> for BeatmapsetSearchParametersVisitor {
             video,
             storyboard,
             nsfw,
+            played,
+            ranks: ranks.unwrap_or_default(),
+            recommended: recommended.unwrap_or(false),
+            converts: converts.unwrap_or(false),
+            follows: follows.unwrap_or(false),
+            spotlights: spotlights.unwrap_or(false),
+            featured_artists: featured_artists.unwrap_or(false),
             sort,
             descending,
         };
@@ -1158,6 +1553,8 @@ impl<'de> Deserialize<'de> for BeatmapsetSearchParameters {
 // #[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
 pub struct BeatmapsetSearchResult {
     cursor: Option<Cursor>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cursor_string: Option<String>,
     /// All mapsets of the current page
     #[serde(rename(serialize = "beatmapsets"))]
     pub mapsets: Vec<Beatmapset>,
@@ -1172,50 +1569,67 @@ impl BeatmapsetSearchResult {
     /// retrievable via [`get_next`](BeatmapsetSearchResult::get_next).
     #[inline]
     pub fn has_more(&self) -> bool {
-        self.cursor.is_some()
+        self.cursor.is_some() || self.cursor_string.is_some()
+    }
+
+    /// If [`has_more`](BeatmapsetSearchResult::has_more) is true, returns a token
+    /// that contains the cursor and all parameters of this search.
+    ///
+    /// The token can be serialized to continue the search at a later point
+    /// through [`GetBeatmapsetSearch::continuation`](crate::request::GetBeatmapsetSearch::continuation).
+    #[inline]
+    pub fn continuation(&self) -> Option<BeatmapsetSearchContinuation> {
+        self.has_more().then(|| BeatmapsetSearchContinuation {
+            cursor: self.cursor.clone(),
+            cursor_string: self.cursor_string.clone(),
+            params: self.params.clone(),
+        })
     }
 
     /// If [`has_more`](BeatmapsetSearchResult::has_more) is true, the API can provide
     /// the next set of search results and this method will request them.
     /// Otherwise, this method returns `None`.
     pub async fn get_next(&self, osu: &Osu) -> Option<OsuResult<BeatmapsetSearchResult>> {
-        let cursor = self.cursor.as_ref()?.to_owned();
-        let params = &self.params;
-
-        let mut fut = osu
-            .beatmapset_search()
-            .cursor(cursor)
-            .video(params.video)
-            .storyboard(params.storyboard)
-            .nsfw(params.nsfw)
-            .sort(params.sort, params.descending);
-
-        if let Some(ref query) = params.query {
-            fut = fut.query(query);
-        }
-
-        if let Some(mode) = params.mode.map(GameMode::from) {
-            fut = fut.mode(mode);
-        }
+        let continuation = self.continuation()?;
 
-        match params.status {
-            None => {}
-            Some(SearchRankStatus::Specific(status)) => fut = fut.status(status),
-            Some(SearchRankStatus::Any) => fut = fut.any_status(),
-        }
-
-        if let Some(genre) = params.genre {
-            fut = fut.genre(Genre::try_from(genre).unwrap());
-        }
-
-        if let Some(language) = params.language {
-            fut = fut.language(Language::try_from(language).unwrap());
-        }
-
-        Some(fut.await)
+        Some(osu.beatmapset_search().continuation(continuation).await)
     }
 }
 
+/// Opaque token to continue a beatmapset search with all of its parameters,
+/// see [`BeatmapsetSearchResult::continuation`].
+///
+/// It can be (de)serialized so that the search can be resumed at a later point,
+/// e.g. in a later HTTP request of a web frontend.
+///
+/// ## Example
+///
+/// ```no_run
+/// # use rosu_v2::prelude::*;
+/// # let _ = async {
+/// # let osu: Osu = unimplemented!();
+/// let result = osu.beatmapset_search().query("artist=camellia").await?;
+///
+/// if let Some(continuation) = result.continuation() {
+///     let token = serde_json::to_string(&continuation).unwrap();
+///
+///     // ... later on
+///
+///     let continuation: BeatmapsetSearchContinuation = serde_json::from_str(&token).unwrap();
+///     let next_page = osu.beatmapset_search().continuation(continuation).await?;
+/// }
+/// # Ok::<_, OsuError>(())
+/// # };
+/// ```
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct BeatmapsetSearchContinuation {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) cursor: Option<Cursor>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) cursor_string: Option<String>,
+    pub(crate) params: BeatmapsetSearchParameters,
+}
+
 struct BeatmapsetSearchResultVisitor;
 
 impl<'de> Visitor<'de> for BeatmapsetSearchResultVisitor {
@@ -1228,6 +1642,7 @@ impl<'de> Visitor<'de> for BeatmapsetSearchResultVisitor {
     fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
         let mut mapsets = None;
         let mut cursor = None;
+        let mut cursor_string = None;
         let mut params = None;
         let mut total = None;
 
@@ -1235,6 +1650,7 @@ impl<'de> Visitor<'de> for BeatmapsetSearchResultVisitor {
             match key {
                 "beatmapsets" => mapsets = Some(map.next_value()?),
                 "cursor" => cursor = map.next_value()?,
+                "cursor_string" => cursor_string = map.next_value()?,
                 "search" => params = Some(map.next_value()?),
                 "total" => total = Some(map.next_value()?),
                 _ => {
@@ -1249,6 +1665,7 @@ impl<'de> Visitor<'de> for BeatmapsetSearchResultVisitor {
 
         Ok(BeatmapsetSearchResult {
             cursor,
+            cursor_string,
             mapsets,
             params,
             total,
@@ -1669,6 +2086,10 @@ impl<'de> Visitor<'de> for DescriptionVisitor {
     }
 }
 
+fn preview_audio_url(mapset_id: u32) -> String {
+    format!("https://b.ppy.sh/preview/{}.mp3", mapset_id)
+}
+
 fn flatten_description<'de, D: Deserializer<'de>>(d: D) -> Result<Option<String>, D::Error> {
     d.deserialize_option(DescriptionVisitor)
 }
@@ -1693,6 +2114,7 @@ mod tests {
     fn ser_de_search_result_any_status() {
         let search_result = BeatmapsetSearchResult {
             cursor: None,
+            cursor_string: None,
             mapsets: Vec::new(),
             params: BeatmapsetSearchParameters {
                 query: Some("my query".to_owned()),
@@ -1703,6 +2125,13 @@ mod tests {
                 video: true,
                 storyboard: false,
                 nsfw: false,
+                played: Some(false),
+                ranks: vec![Grade::XH, Grade::A],
+                recommended: true,
+                converts: false,
+                follows: true,
+                spotlights: false,
+                featured_artists: true,
                 sort: BeatmapsetSearchSort::RankedDate,
                 descending: false,
             },
@@ -1716,6 +2145,7 @@ mod tests {
     fn ser_de_search_result_specific_status() {
         let search_result = BeatmapsetSearchResult {
             cursor: None,
+            cursor_string: Some("eyJfc2NvcmUiOjEyLjN9".to_owned()),
             mapsets: Vec::new(),
             params: BeatmapsetSearchParameters {
                 query: None,
@@ -1726,12 +2156,31 @@ mod tests {
                 video: true,
                 storyboard: false,
                 nsfw: true,
+                played: None,
+                ranks: Vec::new(),
+                recommended: false,
+                converts: false,
+                follows: false,
+                spotlights: false,
+                featured_artists: false,
                 sort: BeatmapsetSearchSort::Playcount,
                 descending: true,
             },
             total: 42,
         };
 
-        ser_de(search_result);
+        ser_de(search_result.clone());
+        ser_de(search_result.continuation().unwrap());
+    }
+
+    #[test]
+    fn covers_from_mapset_id() {
+        let covers = BeatmapsetCovers::new(1_200_000);
+
+        assert_eq!(
+            covers.get(CoverSize::Card2x),
+            "https://assets.ppy.sh/beatmaps/1200000/covers/card@2x.jpg"
+        );
+        assert_eq!(covers.slim_cover, CoverSize::SlimCover.url(1_200_000));
     }
 }
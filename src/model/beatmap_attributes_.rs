@@ -0,0 +1,288 @@
+use super::{
+    beatmap_::Beatmap,
+    game_mod::{DifficultyAdjust, GameMod, GameModsIntermediate},
+    GameMode, GameMods,
+};
+
+/// Attributes of a [`Beatmap`] after applying mods.
+///
+/// Retrieved through [`Beatmap::attributes_with_mods`] or
+/// [`Beatmap::attributes_with_lazer_mods`]. Unlike
+/// [`BeatmapDifficultyAttributes`](crate::model::beatmap::BeatmapDifficultyAttributes)
+/// these are calculated locally and don't require a request.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct BeatmapAttributes {
+    pub ar: f32,
+    pub od: f32,
+    pub cs: f32,
+    pub hp: f32,
+    pub bpm: f32,
+    /// The rate at which the map is played, i.e. 1.5 for `DT`
+    pub clock_rate: f64,
+    pub seconds_drain: u32,
+    pub seconds_total: u32,
+    pub hit_windows: HitWindows,
+}
+
+/// Hit windows in milliseconds of real time after applying mods.
+///
+/// Each window describes the maximum distance to the object's time
+/// for the corresponding judgement. Windows that don't exist
+/// for the mode are `None`.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct HitWindows {
+    /// Time between an object appearing and having to be hit.
+    /// Only available for osu!standard and osu!catch.
+    pub approach: Option<f32>,
+    /// Only available for osu!mania.
+    pub perfect: Option<f32>,
+    pub great: Option<f32>,
+    /// Only available for osu!mania.
+    pub good: Option<f32>,
+    pub ok: Option<f32>,
+    pub meh: Option<f32>,
+}
+
+const HR_MULTIPLIER: f64 = 1.4;
+const HR_CS_MULTIPLIER: f64 = 1.3;
+const EZ_MULTIPLIER: f64 = 0.5;
+
+const PREEMPT_MIN: f64 = 450.0;
+const PREEMPT_MID: f64 = 1200.0;
+const PREEMPT_MAX: f64 = 1800.0;
+
+impl BeatmapAttributes {
+    pub(crate) fn new(map: &Beatmap, mods: &GameModsIntermediate) -> Self {
+        let mode = map.mode;
+        let legacy = mods.legacy();
+        let clock_rate = mods.clock_rate();
+
+        let mut ar = f64::from(map.ar);
+        let mut od = f64::from(map.od);
+        let mut cs = f64::from(map.cs);
+        let mut hp = f64::from(map.hp);
+
+        let adjust = mods
+            .typed(mode)
+            .into_iter()
+            .find_map(|gamemod| match gamemod {
+                GameMod::DifficultyAdjust(adjust) => Some(adjust),
+                _ => None,
+            });
+
+        if let Some(adjust) = adjust {
+            let (cs_, ar_, hp_, od_) = match adjust {
+                DifficultyAdjust::Osu {
+                    circle_size,
+                    approach_rate,
+                    drain_rate,
+                    overall_difficulty,
+                    ..
+                }
+                | DifficultyAdjust::Catch {
+                    circle_size,
+                    approach_rate,
+                    drain_rate,
+                    overall_difficulty,
+                    ..
+                } => (circle_size, approach_rate, drain_rate, overall_difficulty),
+                DifficultyAdjust::Taiko {
+                    drain_rate,
+                    overall_difficulty,
+                    ..
+                }
+                | DifficultyAdjust::Mania {
+                    drain_rate,
+                    overall_difficulty,
+                    ..
+                } => (None, None, drain_rate, overall_difficulty),
+            };
+
+            cs = cs_.unwrap_or(cs);
+            ar = ar_.unwrap_or(ar);
+            hp = hp_.unwrap_or(hp);
+            od = od_.unwrap_or(od);
+        }
+
+        let hr = legacy.contains(GameMods::HardRock);
+        let ez = legacy.contains(GameMods::Easy);
+
+        let multiplier = if hr {
+            HR_MULTIPLIER
+        } else if ez {
+            EZ_MULTIPLIER
+        } else {
+            1.0
+        };
+
+        // The CS of osu!mania maps is the key count
+        if mode != GameMode::Mania {
+            if hr {
+                cs = (cs * HR_CS_MULTIPLIER).min(10.0);
+            } else if ez {
+                cs *= EZ_MULTIPLIER;
+            }
+        }
+
+        ar = (ar * multiplier).min(10.0);
+        hp = (hp * multiplier).min(10.0);
+
+        // osu!mania applies HR and EZ to its hit windows instead of the OD
+        if mode != GameMode::Mania {
+            od = (od * multiplier).min(10.0);
+        }
+
+        let mut hit_windows = HitWindows::default();
+
+        match mode {
+            GameMode::Osu | GameMode::Catch => {
+                let preempt = difficulty_range(ar, PREEMPT_MAX, PREEMPT_MID, PREEMPT_MIN);
+                let preempt = preempt / clock_rate;
+                ar = preempt_to_ar(preempt);
+                hit_windows.approach = Some(preempt as f32);
+
+                if mode == GameMode::Osu {
+                    let great = (80.0 - 6.0 * od) / clock_rate;
+                    let ok = (140.0 - 8.0 * od) / clock_rate;
+                    let meh = (200.0 - 10.0 * od) / clock_rate;
+                    od = (80.0 - great) / 6.0;
+
+                    hit_windows.great = Some(great as f32);
+                    hit_windows.ok = Some(ok as f32);
+                    hit_windows.meh = Some(meh as f32);
+                }
+            }
+            GameMode::Taiko => {
+                let great = difficulty_range(od, 50.0, 35.0, 20.0) / clock_rate;
+                let ok = difficulty_range(od, 120.0, 80.0, 50.0) / clock_rate;
+                od = (50.0 - great) / 3.0;
+
+                hit_windows.great = Some(great as f32);
+                hit_windows.ok = Some(ok as f32);
+            }
+            GameMode::Mania => {
+                // Windows are not affected by the clock rate in osu!mania
+                let multiplier = if hr {
+                    1.0 / HR_MULTIPLIER
+                } else if ez {
+                    HR_MULTIPLIER
+                } else {
+                    1.0
+                };
+
+                let window = |base: f64| ((base - 3.0 * od) * multiplier) as f32;
+
+                hit_windows.perfect = Some((16.0 * multiplier) as f32);
+                hit_windows.great = Some(window(64.0));
+                hit_windows.good = Some(window(97.0));
+                hit_windows.ok = Some(window(127.0));
+                hit_windows.meh = Some(window(151.0));
+            }
+        }
+
+        Self {
+            ar: ar as f32,
+            od: od as f32,
+            cs: cs as f32,
+            hp: hp as f32,
+            bpm: (f64::from(map.bpm) * clock_rate) as f32,
+            clock_rate,
+            seconds_drain: (f64::from(map.seconds_drain) / clock_rate).round() as u32,
+            seconds_total: (f64::from(map.seconds_total) / clock_rate).round() as u32,
+            hit_windows,
+        }
+    }
+}
+
+fn difficulty_range(difficulty: f64, min: f64, mid: f64, max: f64) -> f64 {
+    if difficulty > 5.0 {
+        mid + (max - mid) * (difficulty - 5.0) / 5.0
+    } else if difficulty < 5.0 {
+        mid - (mid - min) * (5.0 - difficulty) / 5.0
+    } else {
+        mid
+    }
+}
+
+fn preempt_to_ar(preempt: f64) -> f64 {
+    if preempt > PREEMPT_MID {
+        5.0 - (preempt - PREEMPT_MID) / (PREEMPT_MAX - PREEMPT_MID) * 5.0
+    } else {
+        5.0 + (PREEMPT_MID - preempt) / (PREEMPT_MID - PREEMPT_MIN) * 5.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{
+        game_mod::{GameModIntermediate, ModSettingValue},
+        test_util::{assert_approx, map},
+    };
+
+    #[test]
+    fn osu_hrdt() {
+        let attrs =
+            map(GameMode::Osu).attributes_with_mods(GameMods::HardRock | GameMods::NightCore);
+
+        assert_approx(attrs.ar, 11.0);
+        assert_approx(attrs.od, 100.0 / 9.0);
+        assert_approx(attrs.cs, 5.2);
+        assert_approx(attrs.hp, 8.4);
+        assert_approx(attrs.bpm, 270.0);
+        assert_eq!(attrs.seconds_drain, 60);
+        assert_eq!(attrs.seconds_total, 67);
+        assert_approx(attrs.hit_windows.approach.unwrap(), 300.0);
+        assert_approx(attrs.hit_windows.great.unwrap(), 40.0 / 3.0);
+    }
+
+    #[test]
+    fn osu_nomod() {
+        let map = map(GameMode::Osu);
+        let attrs = map.attributes_with_mods(GameMods::Hidden);
+
+        assert_approx(attrs.ar, map.ar);
+        assert_approx(attrs.od, map.od);
+        assert_approx(attrs.bpm, map.bpm);
+        assert_eq!(attrs.seconds_drain, map.seconds_drain);
+    }
+
+    #[test]
+    fn lazer_rate_and_difficulty_adjust() {
+        let mut ht = GameModIntermediate::new("HT");
+        ht.settings
+            .insert("speed_change".to_owned(), ModSettingValue::Number(0.8));
+
+        let mut da = GameModIntermediate::new("DA");
+        da.settings
+            .insert("approach_rate".to_owned(), ModSettingValue::Number(5.0));
+
+        let mods: GameModsIntermediate = vec![ht, da].into();
+        let attrs = map(GameMode::Osu).attributes_with_lazer_mods(&mods);
+
+        assert_approx(attrs.bpm, 144.0);
+        assert_approx(attrs.hit_windows.approach.unwrap(), 1500.0);
+        assert_approx(attrs.ar, 2.5);
+        assert_eq!(attrs.seconds_total, 125);
+    }
+
+    #[test]
+    fn mania_hr() {
+        let attrs =
+            map(GameMode::Mania).attributes_with_mods(GameMods::HardRock | GameMods::DoubleTime);
+
+        assert_approx(attrs.cs, 4.0);
+        assert_approx(attrs.od, 8.0);
+        assert_approx(attrs.hit_windows.great.unwrap(), 40.0 / 1.4);
+        assert_eq!(attrs.hit_windows.approach, None);
+    }
+
+    #[test]
+    fn taiko_ez() {
+        let attrs = map(GameMode::Taiko).attributes_with_mods(GameMods::Easy);
+
+        assert_approx(attrs.od, 4.0);
+        assert_approx(attrs.hit_windows.great.unwrap(), 38.0);
+        assert_approx(attrs.hit_windows.ok.unwrap(), 88.0);
+    }
+}
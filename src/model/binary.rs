@@ -0,0 +1,188 @@
+#[cfg(feature = "osu-db")]
+use crate::error::OsuDbError;
+#[cfg(feature = "replay")]
+use crate::error::ReplayError;
+
+use time::OffsetDateTime;
+
+/// Windows ticks i.e. 100ns intervals between 0001-01-01 and 1970-01-01
+const UNIX_EPOCH_TICKS: i64 = 621_355_968_000_000_000;
+
+/// Failed to read a primitive value, converted into the error of the format at hand
+#[derive(Debug)]
+pub(super) enum ReadError {
+    StringPrefix(u8),
+    Uleb128,
+    UnexpectedEof,
+    Utf8,
+}
+
+#[cfg(feature = "replay")]
+impl From<ReadError> for ReplayError {
+    fn from(err: ReadError) -> Self {
+        match err {
+            ReadError::StringPrefix(byte) => Self::StringPrefix(byte),
+            ReadError::Uleb128 => Self::Uleb128,
+            ReadError::UnexpectedEof => Self::UnexpectedEof,
+            ReadError::Utf8 => Self::Utf8,
+        }
+    }
+}
+
+#[cfg(feature = "osu-db")]
+impl From<ReadError> for OsuDbError {
+    fn from(err: ReadError) -> Self {
+        match err {
+            ReadError::StringPrefix(byte) => Self::StringPrefix(byte),
+            ReadError::Uleb128 => Self::Uleb128,
+            ReadError::UnexpectedEof => Self::UnexpectedEof,
+            ReadError::Utf8 => Self::Utf8,
+        }
+    }
+}
+
+pub(super) struct Reader<'b> {
+    pub(super) bytes: &'b [u8],
+}
+
+impl<'b> Reader<'b> {
+    pub(super) fn take(&mut self, len: usize) -> Result<&'b [u8], ReadError> {
+        if self.bytes.len() < len {
+            return Err(ReadError::UnexpectedEof);
+        }
+
+        let (taken, rest) = self.bytes.split_at(len);
+        self.bytes = rest;
+
+        Ok(taken)
+    }
+
+    fn array<const N: usize>(&mut self) -> Result<[u8; N], ReadError> {
+        let mut array = [0; N];
+        array.copy_from_slice(self.take(N)?);
+
+        Ok(array)
+    }
+
+    pub(super) fn u8(&mut self) -> Result<u8, ReadError> {
+        self.array().map(u8::from_le_bytes)
+    }
+
+    pub(super) fn u16(&mut self) -> Result<u16, ReadError> {
+        self.array().map(u16::from_le_bytes)
+    }
+
+    pub(super) fn u32(&mut self) -> Result<u32, ReadError> {
+        self.array().map(u32::from_le_bytes)
+    }
+
+    #[cfg(feature = "replay")]
+    pub(super) fn u64(&mut self) -> Result<u64, ReadError> {
+        self.array().map(u64::from_le_bytes)
+    }
+
+    pub(super) fn i64(&mut self) -> Result<i64, ReadError> {
+        self.array().map(i64::from_le_bytes)
+    }
+
+    #[cfg(feature = "osu-db")]
+    pub(super) fn f32(&mut self) -> Result<f32, ReadError> {
+        self.array().map(f32::from_le_bytes)
+    }
+
+    pub(super) fn f64(&mut self) -> Result<f64, ReadError> {
+        self.array().map(f64::from_le_bytes)
+    }
+
+    #[cfg(feature = "osu-db")]
+    pub(super) fn bool(&mut self) -> Result<bool, ReadError> {
+        self.u8().map(|byte| byte != 0)
+    }
+
+    fn uleb128(&mut self) -> Result<usize, ReadError> {
+        let mut value = 0;
+        let mut shift = 0;
+
+        loop {
+            let byte = self.u8()?;
+            let bits = ((byte & 0x7F) as usize)
+                .checked_shl(shift)
+                .filter(|bits| bits >> shift == (byte & 0x7F) as usize)
+                .ok_or(ReadError::Uleb128)?;
+
+            value |= bits;
+
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+
+            shift += 7;
+        }
+    }
+
+    pub(super) fn string(&mut self) -> Result<Option<String>, ReadError> {
+        match self.u8()? {
+            0x00 => Ok(None),
+            0x0B => {
+                let len = self.uleb128()?;
+                let bytes = self.take(len)?.to_vec();
+
+                String::from_utf8(bytes)
+                    .map(Some)
+                    .map_err(|_| ReadError::Utf8)
+            }
+            byte => Err(ReadError::StringPrefix(byte)),
+        }
+    }
+}
+
+fn write_uleb128(bytes: &mut Vec<u8>, mut value: usize) {
+    loop {
+        let byte = (value & 0x7F) as u8;
+        value >>= 7;
+
+        if value == 0 {
+            return bytes.push(byte);
+        }
+
+        bytes.push(byte | 0x80);
+    }
+}
+
+pub(super) fn write_string(bytes: &mut Vec<u8>, s: Option<&str>) {
+    match s {
+        Some(s) => {
+            bytes.push(0x0B);
+            write_uleb128(bytes, s.len());
+            bytes.extend_from_slice(s.as_bytes());
+        }
+        None => bytes.push(0x00),
+    }
+}
+
+pub(super) fn ticks_to_datetime(ticks: i64) -> Option<OffsetDateTime> {
+    let nanos = (ticks as i128 - UNIX_EPOCH_TICKS as i128) * 100;
+
+    OffsetDateTime::from_unix_timestamp_nanos(nanos).ok()
+}
+
+#[cfg(any(feature = "replay", test))]
+pub(super) fn datetime_to_ticks(datetime: OffsetDateTime) -> i64 {
+    (datetime.unix_timestamp_nanos() / 100) as i64 + UNIX_EPOCH_TICKS
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn uleb128_overflow() {
+        let mut bytes = vec![0x0B];
+        bytes.extend_from_slice(&[0xFF; 10]);
+        bytes.push(0x01);
+
+        let mut reader = Reader { bytes: &bytes };
+
+        assert!(matches!(reader.string(), Err(ReadError::Uleb128)));
+    }
+}
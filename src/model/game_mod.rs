@@ -0,0 +1,1142 @@
+use super::{mods::util, GameMode, GameMods};
+use crate::error::{OsuError, ParsingError};
+
+use serde::{
+    de::{Error, IgnoredAny, MapAccess, SeqAccess, Unexpected, Visitor},
+    ser::SerializeMap,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+use std::{collections::BTreeMap, fmt, iter::FromIterator, str::FromStr};
+
+#[cfg(feature = "rkyv")]
+use rkyv::{Archive, Deserialize as RkyvDeserialize, Serialize as RkyvSerialize};
+
+/// Settings of a mod, mapping the setting's name to its value
+pub type GameModSettings = BTreeMap<String, ModSettingValue>;
+
+/// The value of a single mod setting
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
+#[serde(untagged)]
+pub enum ModSettingValue {
+    Bool(bool),
+    Number(f64),
+    String(String),
+}
+
+impl ModSettingValue {
+    fn as_bool(&self) -> Option<bool> {
+        match self {
+            Self::Bool(value) => Some(*value),
+            Self::Number(_) | Self::String(_) => None,
+        }
+    }
+
+    fn as_f64(&self) -> Option<f64> {
+        match self {
+            Self::Number(value) => Some(*value),
+            Self::String(value) => value.parse().ok(),
+            Self::Bool(_) => None,
+        }
+    }
+
+    fn as_u32(&self) -> Option<u32> {
+        match self {
+            Self::Number(value) if *value >= 0.0 && *value <= u32::MAX as f64 => {
+                Some(*value as u32)
+            }
+            Self::String(value) => value.parse().ok(),
+            Self::Number(_) | Self::Bool(_) => None,
+        }
+    }
+
+    fn to_string_value(&self) -> String {
+        match self {
+            Self::Bool(value) => value.to_string(),
+            Self::Number(value) => value.to_string(),
+            Self::String(value) => value.clone(),
+        }
+    }
+}
+
+/// A mod as provided by the API, consisting of an acronym and its settings.
+///
+/// Unlike [`GameMod`], this type does not depend on a [`GameMode`].
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
+pub struct GameModIntermediate {
+    /// Uppercase acronym of the mod, e.g. `"DT"`
+    pub acronym: String,
+    /// Settings of the mod, empty if the mod uses its default settings
+    pub settings: GameModSettings,
+}
+
+impl GameModIntermediate {
+    /// Create a new mod without settings
+    #[inline]
+    pub fn new(acronym: impl Into<String>) -> Self {
+        let mut acronym = acronym.into();
+        acronym.make_ascii_uppercase();
+
+        Self {
+            acronym,
+            settings: GameModSettings::new(),
+        }
+    }
+
+    /// Returns the legacy bitflag of the mod if it has one.
+    ///
+    /// Note that this ignores settings.
+    pub fn legacy(&self) -> Option<GameMods> {
+        let mods = match self.acronym.as_str() {
+            "NF" => GameMods::NoFail,
+            "EZ" => GameMods::Easy,
+            "TD" => GameMods::TouchDevice,
+            "HD" => GameMods::Hidden,
+            "HR" => GameMods::HardRock,
+            "SD" => GameMods::SuddenDeath,
+            "DT" => GameMods::DoubleTime,
+            "RX" => GameMods::Relax,
+            "HT" | "DC" => GameMods::HalfTime,
+            "NC" => GameMods::NightCore,
+            "FL" => GameMods::Flashlight,
+            "AT" => GameMods::Autoplay,
+            "SO" => GameMods::SpunOut,
+            "AP" => GameMods::Autopilot,
+            "PF" => GameMods::Perfect,
+            "FI" => GameMods::FadeIn,
+            "RD" => GameMods::Random,
+            "CN" => GameMods::Cinema,
+            "TP" => GameMods::Target,
+            "SV2" => GameMods::ScoreV2,
+            "MR" => GameMods::Mirror,
+            "1K" => GameMods::Key1,
+            "2K" => GameMods::Key2,
+            "3K" => GameMods::Key3,
+            "4K" => GameMods::Key4,
+            "5K" => GameMods::Key5,
+            "6K" => GameMods::Key6,
+            "7K" => GameMods::Key7,
+            "8K" => GameMods::Key8,
+            "9K" => GameMods::Key9,
+            "DS" => GameMods::KeyCoop,
+            _ => return None,
+        };
+
+        Some(mods)
+    }
+
+    /// Whether the mod can be represented by legacy bitflags without losing information
+    pub fn is_legacy(&self) -> bool {
+        if self.legacy().is_none() {
+            return false;
+        }
+
+        self.settings.iter().all(|(name, value)| {
+            // Legacy rate mods are fine as long as they use the default rate
+            match self.default_rate() {
+                Some(rate) => name == "speed_change" && value.as_f64() == Some(rate),
+                None => false,
+            }
+        })
+    }
+
+    /// The clock rate of rate adjusting mods without settings
+    fn default_rate(&self) -> Option<f64> {
+        match self.acronym.as_str() {
+            "DT" | "NC" => Some(1.5),
+            "HT" | "DC" => Some(0.75),
+            _ => None,
+        }
+    }
+
+    fn legacy_acronym(mods: GameMods) -> Option<&'static str> {
+        let acronym = match mods {
+            GameMods::NoFail => "NF",
+            GameMods::Easy => "EZ",
+            GameMods::TouchDevice => "TD",
+            GameMods::Hidden => "HD",
+            GameMods::HardRock => "HR",
+            GameMods::SuddenDeath => "SD",
+            GameMods::DoubleTime => "DT",
+            GameMods::Relax => "RX",
+            GameMods::HalfTime => "HT",
+            GameMods::NightCore => "NC",
+            GameMods::Flashlight => "FL",
+            GameMods::Autoplay => "AT",
+            GameMods::SpunOut => "SO",
+            GameMods::Autopilot => "AP",
+            GameMods::Perfect => "PF",
+            GameMods::FadeIn => "FI",
+            GameMods::Random => "RD",
+            GameMods::Cinema => "CN",
+            GameMods::Target => "TP",
+            GameMods::ScoreV2 => "SV2",
+            GameMods::Mirror => "MR",
+            GameMods::Key1 => "1K",
+            GameMods::Key2 => "2K",
+            GameMods::Key3 => "3K",
+            GameMods::Key4 => "4K",
+            GameMods::Key5 => "5K",
+            GameMods::Key6 => "6K",
+            GameMods::Key7 => "7K",
+            GameMods::Key8 => "8K",
+            GameMods::Key9 => "9K",
+            GameMods::KeyCoop => "DS",
+            _ => return None,
+        };
+
+        Some(acronym)
+    }
+}
+
+impl fmt::Display for GameModIntermediate {
+    /// Writes the acronym, followed by settings that differ from the
+    /// defaults in parentheses, e.g. `DT(1.3x)`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.acronym)?;
+
+        let default_rate = self.default_rate();
+        let mut first = true;
+
+        for (name, value) in self.settings.iter() {
+            let rate = match (name.as_str(), default_rate) {
+                ("speed_change", Some(default)) => value.as_f64().map(|rate| (rate, default)),
+                _ => None,
+            };
+
+            if matches!(rate, Some((rate, default)) if rate == default) {
+                continue;
+            }
+
+            f.write_str(if first { "(" } else { ", " })?;
+            first = false;
+
+            match rate {
+                Some((rate, _)) => write!(f, "{}x", rate)?,
+                None => write!(f, "{}={}", name, value.to_string_value())?,
+            }
+        }
+
+        if !first {
+            f.write_str(")")?;
+        }
+
+        Ok(())
+    }
+}
+
+struct GameModIntermediateVisitor;
+
+impl<'de> Visitor<'de> for GameModIntermediateVisitor {
+    type Value = GameModIntermediate;
+
+    #[inline]
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("an acronym or a map containing an acronym and settings")
+    }
+
+    #[inline]
+    fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+        Ok(GameModIntermediate::new(v))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut acronym: Option<String> = None;
+        let mut settings = None;
+
+        while let Some(key) = map.next_key::<&str>()? {
+            match key {
+                "acronym" => acronym = Some(map.next_value()?),
+                "settings" => settings = Some(map.next_value()?),
+                _ => {
+                    let _: IgnoredAny = map.next_value()?;
+                }
+            }
+        }
+
+        let mut gamemod =
+            GameModIntermediate::new(acronym.ok_or_else(|| Error::missing_field("acronym"))?);
+        gamemod.settings = settings.unwrap_or_default();
+
+        Ok(gamemod)
+    }
+}
+
+impl<'de> Deserialize<'de> for GameModIntermediate {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        d.deserialize_any(GameModIntermediateVisitor)
+    }
+}
+
+impl Serialize for GameModIntermediate {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        let len = 1 + !self.settings.is_empty() as usize;
+        let mut map = s.serialize_map(Some(len))?;
+        map.serialize_entry("acronym", &self.acronym)?;
+
+        if !self.settings.is_empty() {
+            map.serialize_entry("settings", &self.settings)?;
+        }
+
+        map.end()
+    }
+}
+
+/// A collection of [`GameModIntermediate`]s, representing mods as provided by the API.
+///
+/// # Example
+/// ```
+/// use rosu_v2::model::{GameMods, GameModsIntermediate};
+/// use std::str::FromStr;
+///
+/// let mods = GameModsIntermediate::from_str("HDDTCL").unwrap();
+/// assert_eq!(mods.to_string(), "HDDTCL");
+/// assert!(mods.contains("DT"));
+/// assert_eq!(mods.legacy(), GameMods::Hidden | GameMods::DoubleTime);
+/// assert_eq!(mods.try_legacy(), None);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
+pub struct GameModsIntermediate {
+    inner: Vec<GameModIntermediate>,
+}
+
+impl GameModsIntermediate {
+    /// Create an empty mod collection
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a mod
+    #[inline]
+    pub fn push(&mut self, gamemod: GameModIntermediate) {
+        self.inner.push(gamemod);
+    }
+
+    /// Returns the amount of contained mods
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Whether no mods are contained
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// Iterate over the contained mods
+    #[inline]
+    pub fn iter(&self) -> std::slice::Iter<'_, GameModIntermediate> {
+        self.inner.iter()
+    }
+
+    /// Whether a mod with the given acronym is contained
+    #[inline]
+    pub fn contains(&self, acronym: &str) -> bool {
+        self.get(acronym).is_some()
+    }
+
+    /// Returns the mod with the given acronym
+    #[inline]
+    pub fn get(&self, acronym: &str) -> Option<&GameModIntermediate> {
+        self.inner
+            .iter()
+            .find(|gamemod| gamemod.acronym.eq_ignore_ascii_case(acronym))
+    }
+
+    /// Convert into legacy bitflags, dropping all mods and settings that can't be represented.
+    pub fn legacy(&self) -> GameMods {
+        self.inner
+            .iter()
+            .filter_map(GameModIntermediate::legacy)
+            .fold(GameMods::NoMod, |mods, next| mods | next)
+    }
+
+    /// Convert into legacy bitflags if that's possible without losing information.
+    pub fn try_legacy(&self) -> Option<GameMods> {
+        self.inner
+            .iter()
+            .all(GameModIntermediate::is_legacy)
+            .then(|| self.legacy())
+    }
+
+    /// Returns the clock rate of the mods i.e. 1.5 for default `DT`.
+    pub fn clock_rate(&self) -> f64 {
+        self.inner
+            .iter()
+            .find_map(|gamemod| {
+                let default = gamemod.default_rate()?;
+
+                let rate = gamemod
+                    .settings
+                    .get("speed_change")
+                    .and_then(ModSettingValue::as_f64);
+
+                Some(rate.unwrap_or(default))
+            })
+            .unwrap_or(1.0)
+    }
+
+    /// Convert into [`GameMod`]s with typed settings for the given mode
+    #[inline]
+    pub fn typed(&self, mode: GameMode) -> Vec<GameMod> {
+        self.inner
+            .iter()
+            .map(|gamemod| GameMod::new(gamemod, mode))
+            .collect()
+    }
+}
+
+impl From<GameMods> for GameModsIntermediate {
+    fn from(mods: GameMods) -> Self {
+        let inner = mods
+            .iter()
+            .filter_map(GameModIntermediate::legacy_acronym)
+            .map(GameModIntermediate::new)
+            .collect();
+
+        Self { inner }
+    }
+}
+
+impl From<Vec<GameModIntermediate>> for GameModsIntermediate {
+    #[inline]
+    fn from(inner: Vec<GameModIntermediate>) -> Self {
+        Self { inner }
+    }
+}
+
+impl FromIterator<GameModIntermediate> for GameModsIntermediate {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = GameModIntermediate>>(iter: I) -> Self {
+        Self {
+            inner: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl IntoIterator for GameModsIntermediate {
+    type Item = GameModIntermediate;
+    type IntoIter = std::vec::IntoIter<GameModIntermediate>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.into_iter()
+    }
+}
+
+impl<'m> IntoIterator for &'m GameModsIntermediate {
+    type Item = &'m GameModIntermediate;
+    type IntoIter = std::slice::Iter<'m, GameModIntermediate>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.iter()
+    }
+}
+
+impl fmt::Display for GameModsIntermediate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.inner.is_empty() {
+            return f.write_str("NM");
+        }
+
+        for gamemod in self.inner.iter() {
+            fmt::Display::fmt(gamemod, f)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for GameModsIntermediate {
+    type Err = OsuError;
+
+    /// Parse concatenated acronyms like `"HDDT"`, optionally separated by
+    /// whitespace, commas, or plus signs.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let upper = util::to_uppercase(s);
+
+        if upper == "NM" || upper == "NOMOD" {
+            return Ok(Self::default());
+        }
+
+        let mut inner = Vec::new();
+
+        for chunk in upper
+            .split(|c: char| c.is_whitespace() || c == ',' || c == '+')
+            .filter(|chunk| !chunk.is_empty())
+        {
+            let mut rest = chunk;
+
+            while !rest.is_empty() {
+                // Only few acronyms consist of three characters
+                let len = if rest.starts_with("SV2") || rest.starts_with("10K") {
+                    3
+                } else {
+                    2
+                };
+
+                match rest.get(..len) {
+                    Some(acronym) if acronym.chars().all(|c| c.is_ascii_alphanumeric()) => {
+                        inner.push(GameModIntermediate::new(acronym));
+                        rest = &rest[len..];
+                    }
+                    _ => return Err(ParsingError::ModsStr(s.to_owned()).into()),
+                }
+            }
+        }
+
+        Ok(Self { inner })
+    }
+}
+
+struct GameModsIntermediateVisitor;
+
+impl<'de> Visitor<'de> for GameModsIntermediateVisitor {
+    type Value = GameModsIntermediate;
+
+    #[inline]
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a u32, a string, or a sequence of mods")
+    }
+
+    fn visit_str<E: Error>(self, v: &str) -> Result<Self::Value, E> {
+        let mods = match util::parse_u32(v) {
+            Some(n) => GameMods::from_bits(n).map(GameModsIntermediate::from),
+            None => GameModsIntermediate::from_str(v).ok(),
+        };
+
+        mods.ok_or_else(|| {
+            Error::invalid_value(
+                Unexpected::Str(v),
+                &"a stringified u32 representing GameMods or a combination of mod acronyms",
+            )
+        })
+    }
+
+    fn visit_u64<E: Error>(self, v: u64) -> Result<Self::Value, E> {
+        use std::convert::TryInto;
+
+        v.try_into()
+            .ok()
+            .and_then(GameMods::from_bits)
+            .map(GameModsIntermediate::from)
+            .ok_or_else(|| {
+                Error::invalid_value(
+                    Unexpected::Unsigned(v),
+                    &"a valid u32 representing a mod combination",
+                )
+            })
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut inner = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+
+        while let Some(gamemod) = seq.next_element()? {
+            inner.push(gamemod);
+        }
+
+        Ok(GameModsIntermediate { inner })
+    }
+}
+
+impl<'de> Deserialize<'de> for GameModsIntermediate {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        d.deserialize_any(GameModsIntermediateVisitor)
+    }
+}
+
+impl Serialize for GameModsIntermediate {
+    #[inline]
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        Serialize::serialize(&self.inner, s)
+    }
+}
+
+/// Settings of rate adjusting mods i.e. `HT`, `DC`, `DT`, and `NC`
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct RateAdjust {
+    /// The actual rate, i.e. 1.5 for default `DT`
+    pub speed_change: Option<f64>,
+    /// Whether the pitch should be adjusted along with the rate.
+    /// Not available for `NC` and `DC`.
+    pub adjust_pitch: Option<bool>,
+}
+
+/// Settings of the difficulty adjust mod `DA` for each mode
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum DifficultyAdjust {
+    Osu {
+        circle_size: Option<f64>,
+        approach_rate: Option<f64>,
+        drain_rate: Option<f64>,
+        overall_difficulty: Option<f64>,
+        extended_limits: Option<bool>,
+    },
+    Taiko {
+        scroll_speed: Option<f64>,
+        drain_rate: Option<f64>,
+        overall_difficulty: Option<f64>,
+        extended_limits: Option<bool>,
+    },
+    Catch {
+        circle_size: Option<f64>,
+        approach_rate: Option<f64>,
+        drain_rate: Option<f64>,
+        overall_difficulty: Option<f64>,
+        hard_rock_offsets: Option<bool>,
+        extended_limits: Option<bool>,
+    },
+    Mania {
+        drain_rate: Option<f64>,
+        overall_difficulty: Option<f64>,
+        extended_limits: Option<bool>,
+    },
+}
+
+/// Settings of the classic mod `CL` for each mode
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Classic {
+    Osu {
+        no_slider_head_accuracy: Option<bool>,
+        classic_note_lock: Option<bool>,
+        always_play_tail_sample: Option<bool>,
+        fade_hit_circle_early: Option<bool>,
+        classic_health: Option<bool>,
+    },
+    Taiko,
+    Catch,
+    Mania,
+}
+
+/// A mod with typed settings for a specific [`GameMode`].
+///
+/// Mods that are not known or don't have a counterpart in the given mode
+/// are kept as [`GameMod::Other`].
+///
+/// # Example
+/// ```
+/// use rosu_v2::model::{GameMod, GameMode, GameModIntermediate, ModSettingValue, RateAdjust};
+///
+/// let mut dt = GameModIntermediate::new("DT");
+/// dt.settings.insert("speed_change".to_owned(), ModSettingValue::Number(1.3));
+///
+/// let typed = GameMod::new(&dt, GameMode::Osu);
+/// let settings = RateAdjust { speed_change: Some(1.3), adjust_pitch: None };
+///
+/// assert_eq!(typed, GameMod::DoubleTime(settings));
+/// assert_eq!(GameModIntermediate::from(typed), dt);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum GameMod {
+    Easy {
+        retries: Option<u32>,
+    },
+    NoFail,
+    HalfTime(RateAdjust),
+    Daycore(RateAdjust),
+    HardRock,
+    SuddenDeath {
+        restart: Option<bool>,
+    },
+    Perfect {
+        restart: Option<bool>,
+    },
+    DoubleTime(RateAdjust),
+    Nightcore(RateAdjust),
+    Hidden {
+        only_fade_approach_circles: Option<bool>,
+    },
+    Flashlight {
+        follow_delay: Option<f64>,
+        size_multiplier: Option<f64>,
+        combo_based_size: Option<bool>,
+    },
+    FadeIn,
+    Relax,
+    Autopilot,
+    SpunOut,
+    Autoplay,
+    Cinema,
+    TouchDevice,
+    ScoreV2,
+    /// osu!mania key mods, ranging from 1 to 10
+    Key(u8),
+    DualStages,
+    Random {
+        seed: Option<u32>,
+        angle_sharpness: Option<f64>,
+    },
+    Mirror {
+        reflection: Option<String>,
+    },
+    TargetPractice {
+        seed: Option<u32>,
+        metronome: Option<bool>,
+    },
+    DifficultyAdjust(DifficultyAdjust),
+    Classic(Classic),
+    /// Any mod that has no typed representation
+    Other(GameModIntermediate),
+}
+
+impl GameMod {
+    /// Create a typed mod from a [`GameModIntermediate`] for the given mode
+    pub fn new(gamemod: &GameModIntermediate, mode: GameMode) -> Self {
+        let settings = &gamemod.settings;
+        let f64_ = |name: &str| settings.get(name).and_then(ModSettingValue::as_f64);
+        let bool_ = |name: &str| settings.get(name).and_then(ModSettingValue::as_bool);
+        let u32_ = |name: &str| settings.get(name).and_then(ModSettingValue::as_u32);
+
+        let rate = || RateAdjust {
+            speed_change: f64_("speed_change"),
+            adjust_pitch: bool_("adjust_pitch"),
+        };
+
+        let is_mania = mode == GameMode::Mania;
+
+        let typed = match (gamemod.acronym.as_str(), mode) {
+            ("EZ", _) => Self::Easy {
+                retries: u32_("retries"),
+            },
+            ("NF", _) => Self::NoFail,
+            ("HT", _) => Self::HalfTime(rate()),
+            ("DC", _) => Self::Daycore(rate()),
+            ("HR", _) => Self::HardRock,
+            ("SD", _) => Self::SuddenDeath {
+                restart: bool_("restart"),
+            },
+            ("PF", _) => Self::Perfect {
+                restart: bool_("restart"),
+            },
+            ("DT", _) => Self::DoubleTime(rate()),
+            ("NC", _) => Self::Nightcore(rate()),
+            ("HD", _) => Self::Hidden {
+                only_fade_approach_circles: bool_("only_fade_approach_circles"),
+            },
+            ("FL", _) => Self::Flashlight {
+                follow_delay: f64_("follow_delay"),
+                size_multiplier: f64_("size_multiplier"),
+                combo_based_size: bool_("combo_based_size"),
+            },
+            ("FI", GameMode::Mania) => Self::FadeIn,
+            ("RX", GameMode::Osu | GameMode::Taiko | GameMode::Catch) => Self::Relax,
+            ("AP", GameMode::Osu) => Self::Autopilot,
+            ("SO", GameMode::Osu) => Self::SpunOut,
+            ("AT", _) => Self::Autoplay,
+            ("CN", _) => Self::Cinema,
+            ("TD", GameMode::Osu) => Self::TouchDevice,
+            ("SV2", _) => Self::ScoreV2,
+            ("DS", GameMode::Mania) => Self::DualStages,
+            ("RD", GameMode::Osu | GameMode::Taiko | GameMode::Mania) => Self::Random {
+                seed: u32_("seed"),
+                angle_sharpness: f64_("angle_sharpness"),
+            },
+            ("MR", GameMode::Osu | GameMode::Catch | GameMode::Mania) => Self::Mirror {
+                reflection: settings
+                    .get("reflection")
+                    .map(ModSettingValue::to_string_value),
+            },
+            ("TP", GameMode::Osu) => Self::TargetPractice {
+                seed: u32_("seed"),
+                metronome: bool_("metronome"),
+            },
+            ("DA", GameMode::Osu) => Self::DifficultyAdjust(DifficultyAdjust::Osu {
+                circle_size: f64_("circle_size"),
+                approach_rate: f64_("approach_rate"),
+                drain_rate: f64_("drain_rate"),
+                overall_difficulty: f64_("overall_difficulty"),
+                extended_limits: bool_("extended_limits"),
+            }),
+            ("DA", GameMode::Taiko) => Self::DifficultyAdjust(DifficultyAdjust::Taiko {
+                scroll_speed: f64_("scroll_speed"),
+                drain_rate: f64_("drain_rate"),
+                overall_difficulty: f64_("overall_difficulty"),
+                extended_limits: bool_("extended_limits"),
+            }),
+            ("DA", GameMode::Catch) => Self::DifficultyAdjust(DifficultyAdjust::Catch {
+                circle_size: f64_("circle_size"),
+                approach_rate: f64_("approach_rate"),
+                drain_rate: f64_("drain_rate"),
+                overall_difficulty: f64_("overall_difficulty"),
+                hard_rock_offsets: bool_("hard_rock_offsets"),
+                extended_limits: bool_("extended_limits"),
+            }),
+            ("DA", GameMode::Mania) => Self::DifficultyAdjust(DifficultyAdjust::Mania {
+                drain_rate: f64_("drain_rate"),
+                overall_difficulty: f64_("overall_difficulty"),
+                extended_limits: bool_("extended_limits"),
+            }),
+            ("CL", GameMode::Osu) => Self::Classic(Classic::Osu {
+                no_slider_head_accuracy: bool_("no_slider_head_accuracy"),
+                classic_note_lock: bool_("classic_note_lock"),
+                always_play_tail_sample: bool_("always_play_tail_sample"),
+                fade_hit_circle_early: bool_("fade_hit_circle_early"),
+                classic_health: bool_("classic_health"),
+            }),
+            ("CL", GameMode::Taiko) => Self::Classic(Classic::Taiko),
+            ("CL", GameMode::Catch) => Self::Classic(Classic::Catch),
+            ("CL", GameMode::Mania) => Self::Classic(Classic::Mania),
+            (acronym, _) if is_mania && acronym.ends_with('K') => {
+                match acronym[..acronym.len() - 1].parse() {
+                    Ok(keys @ 1..=10) => Self::Key(keys),
+                    _ => Self::Other(gamemod.clone()),
+                }
+            }
+            _ => Self::Other(gamemod.clone()),
+        };
+
+        // Settings that aren't covered by the typed representation must not get lost
+        if !matches!(typed, Self::Other(_)) {
+            let intermediate = GameModIntermediate::from(typed.clone());
+
+            if intermediate.settings.len() != settings.len() {
+                return Self::Other(gamemod.clone());
+            }
+        }
+
+        typed
+    }
+
+    /// Returns the acronym of the mod
+    pub fn acronym(&self) -> &str {
+        match self {
+            Self::Easy { .. } => "EZ",
+            Self::NoFail => "NF",
+            Self::HalfTime(_) => "HT",
+            Self::Daycore(_) => "DC",
+            Self::HardRock => "HR",
+            Self::SuddenDeath { .. } => "SD",
+            Self::Perfect { .. } => "PF",
+            Self::DoubleTime(_) => "DT",
+            Self::Nightcore(_) => "NC",
+            Self::Hidden { .. } => "HD",
+            Self::Flashlight { .. } => "FL",
+            Self::FadeIn => "FI",
+            Self::Relax => "RX",
+            Self::Autopilot => "AP",
+            Self::SpunOut => "SO",
+            Self::Autoplay => "AT",
+            Self::Cinema => "CN",
+            Self::TouchDevice => "TD",
+            Self::ScoreV2 => "SV2",
+            Self::Key(1) => "1K",
+            Self::Key(2) => "2K",
+            Self::Key(3) => "3K",
+            Self::Key(4) => "4K",
+            Self::Key(5) => "5K",
+            Self::Key(6) => "6K",
+            Self::Key(7) => "7K",
+            Self::Key(8) => "8K",
+            Self::Key(9) => "9K",
+            Self::Key(_) => "10K",
+            Self::DualStages => "DS",
+            Self::Random { .. } => "RD",
+            Self::Mirror { .. } => "MR",
+            Self::TargetPractice { .. } => "TP",
+            Self::DifficultyAdjust(_) => "DA",
+            Self::Classic(_) => "CL",
+            Self::Other(gamemod) => &gamemod.acronym,
+        }
+    }
+
+    /// Returns the legacy bitflag of the mod if it has one.
+    ///
+    /// Note that this ignores settings.
+    #[inline]
+    pub fn legacy(&self) -> Option<GameMods> {
+        GameModIntermediate::new(self.acronym()).legacy()
+    }
+}
+
+impl fmt::Display for GameMod {
+    /// Writes the acronym, followed by settings that differ from the
+    /// defaults in parentheses, e.g. `DT(1.3x)`
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&GameModIntermediate::from(self.clone()), f)
+    }
+}
+
+impl From<GameMod> for GameModIntermediate {
+    fn from(gamemod: GameMod) -> Self {
+        let mut settings = GameModSettings::new();
+
+        fn insert_f64(settings: &mut GameModSettings, name: &str, value: Option<f64>) {
+            if let Some(value) = value {
+                settings.insert(name.to_owned(), ModSettingValue::Number(value));
+            }
+        }
+
+        let (acronym, bools) = match gamemod {
+            GameMod::Other(gamemod) => return gamemod,
+            GameMod::Easy { retries } => {
+                insert_f64(&mut settings, "retries", retries.map(f64::from));
+
+                ("EZ", Vec::new())
+            }
+            GameMod::HalfTime(rate)
+            | GameMod::Daycore(rate)
+            | GameMod::DoubleTime(rate)
+            | GameMod::Nightcore(rate) => {
+                insert_f64(&mut settings, "speed_change", rate.speed_change);
+
+                (gamemod.acronym(), vec![("adjust_pitch", rate.adjust_pitch)])
+            }
+            GameMod::SuddenDeath { restart } => ("SD", vec![("restart", restart)]),
+            GameMod::Perfect { restart } => ("PF", vec![("restart", restart)]),
+            GameMod::Hidden {
+                only_fade_approach_circles,
+            } => (
+                "HD",
+                vec![("only_fade_approach_circles", only_fade_approach_circles)],
+            ),
+            GameMod::Flashlight {
+                follow_delay,
+                size_multiplier,
+                combo_based_size,
+            } => {
+                insert_f64(&mut settings, "follow_delay", follow_delay);
+                insert_f64(&mut settings, "size_multiplier", size_multiplier);
+
+                ("FL", vec![("combo_based_size", combo_based_size)])
+            }
+            GameMod::Random {
+                seed,
+                angle_sharpness,
+            } => {
+                insert_f64(&mut settings, "seed", seed.map(f64::from));
+                insert_f64(&mut settings, "angle_sharpness", angle_sharpness);
+
+                ("RD", Vec::new())
+            }
+            GameMod::Mirror { ref reflection } => {
+                if let Some(reflection) = reflection {
+                    settings.insert(
+                        "reflection".to_owned(),
+                        ModSettingValue::String(reflection.clone()),
+                    );
+                }
+
+                ("MR", Vec::new())
+            }
+            GameMod::TargetPractice { seed, metronome } => {
+                insert_f64(&mut settings, "seed", seed.map(f64::from));
+
+                ("TP", vec![("metronome", metronome)])
+            }
+            GameMod::DifficultyAdjust(da) => {
+                let (cs, ar, hp, od, scroll_speed, hr_offsets, extended_limits) = match da {
+                    DifficultyAdjust::Osu {
+                        circle_size,
+                        approach_rate,
+                        drain_rate,
+                        overall_difficulty,
+                        extended_limits,
+                    } => (
+                        circle_size,
+                        approach_rate,
+                        drain_rate,
+                        overall_difficulty,
+                        None,
+                        None,
+                        extended_limits,
+                    ),
+                    DifficultyAdjust::Taiko {
+                        scroll_speed,
+                        drain_rate,
+                        overall_difficulty,
+                        extended_limits,
+                    } => (
+                        None,
+                        None,
+                        drain_rate,
+                        overall_difficulty,
+                        scroll_speed,
+                        None,
+                        extended_limits,
+                    ),
+                    DifficultyAdjust::Catch {
+                        circle_size,
+                        approach_rate,
+                        drain_rate,
+                        overall_difficulty,
+                        hard_rock_offsets,
+                        extended_limits,
+                    } => (
+                        circle_size,
+                        approach_rate,
+                        drain_rate,
+                        overall_difficulty,
+                        None,
+                        hard_rock_offsets,
+                        extended_limits,
+                    ),
+                    DifficultyAdjust::Mania {
+                        drain_rate,
+                        overall_difficulty,
+                        extended_limits,
+                    } => (
+                        None,
+                        None,
+                        drain_rate,
+                        overall_difficulty,
+                        None,
+                        None,
+                        extended_limits,
+                    ),
+                };
+
+                insert_f64(&mut settings, "circle_size", cs);
+                insert_f64(&mut settings, "approach_rate", ar);
+                insert_f64(&mut settings, "drain_rate", hp);
+                insert_f64(&mut settings, "overall_difficulty", od);
+                insert_f64(&mut settings, "scroll_speed", scroll_speed);
+
+                (
+                    "DA",
+                    vec![
+                        ("hard_rock_offsets", hr_offsets),
+                        ("extended_limits", extended_limits),
+                    ],
+                )
+            }
+            GameMod::Classic(Classic::Osu {
+                no_slider_head_accuracy,
+                classic_note_lock,
+                always_play_tail_sample,
+                fade_hit_circle_early,
+                classic_health,
+            }) => (
+                "CL",
+                vec![
+                    ("no_slider_head_accuracy", no_slider_head_accuracy),
+                    ("classic_note_lock", classic_note_lock),
+                    ("always_play_tail_sample", always_play_tail_sample),
+                    ("fade_hit_circle_early", fade_hit_circle_early),
+                    ("classic_health", classic_health),
+                ],
+            ),
+            _ => (gamemod.acronym(), Vec::new()),
+        };
+
+        for (name, value) in bools {
+            if let Some(value) = value {
+                settings.insert(name.to_owned(), ModSettingValue::Bool(value));
+            }
+        }
+
+        Self {
+            acronym: acronym.to_owned(),
+            settings,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn legacy_roundtrip() {
+        let legacy = GameMods::Hidden
+            | GameMods::NightCore
+            | GameMods::Perfect
+            | GameMods::ScoreV2
+            | GameMods::Key4;
+
+        let mods = GameModsIntermediate::from(legacy);
+
+        assert_eq!(mods.to_string(), "HDNCPF4KSV2");
+        assert_eq!(mods.try_legacy(), Some(legacy));
+        assert_eq!(
+            GameModsIntermediate::from(GameMods::NoMod).to_string(),
+            "NM"
+        );
+    }
+
+    #[test]
+    fn from_str() {
+        let mods = GameModsIntermediate::from_str("hdDT, sv2+10K").unwrap();
+        let acronyms: Vec<_> = mods.iter().map(|m| m.acronym.as_str()).collect();
+
+        assert_eq!(acronyms, ["HD", "DT", "SV2", "10K"]);
+        assert!(GameModsIntermediate::from_str("HDD").is_err());
+        assert!(GameModsIntermediate::from_str("nomod").unwrap().is_empty());
+    }
+
+    #[test]
+    fn deserialize() {
+        let json = r#"[
+            { "acronym": "DT", "settings": { "speed_change": 1.3 } },
+            { "acronym": "DA", "settings": { "approach_rate": 9.5, "extended_limits": true } },
+            "HD"
+        ]"#;
+
+        let mods: GameModsIntermediate = serde_json::from_str(json).unwrap();
+
+        assert!((mods.clock_rate() - 1.3).abs() < f64::EPSILON);
+        assert_eq!(mods.legacy(), GameMods::DoubleTime | GameMods::Hidden);
+        assert_eq!(mods.try_legacy(), None);
+
+        let typed = mods.typed(GameMode::Osu);
+
+        let expected = GameMod::DifficultyAdjust(DifficultyAdjust::Osu {
+            circle_size: None,
+            approach_rate: Some(9.5),
+            drain_rate: None,
+            overall_difficulty: None,
+            extended_limits: Some(true),
+        });
+
+        assert_eq!(typed[1], expected);
+
+        let serialized = serde_json::to_string(&mods).unwrap();
+        let deserialized: GameModsIntermediate = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(mods, deserialized);
+
+        let legacy: GameModsIntermediate = serde_json::from_str("72").unwrap();
+        assert_eq!(legacy.to_string(), "HDDT");
+    }
+
+    #[test]
+    fn display_settings() {
+        let json = r#"[
+            { "acronym": "DT", "settings": { "speed_change": 1.3 } },
+            { "acronym": "HT", "settings": { "speed_change": 0.75 } },
+            { "acronym": "DA", "settings": { "approach_rate": 9.5 } },
+            "HD"
+        ]"#;
+
+        let mods: GameModsIntermediate = serde_json::from_str(json).unwrap();
+
+        assert_eq!(mods.to_string(), "DT(1.3x)HTDA(approach_rate=9.5)HD");
+        assert_eq!(mods.typed(GameMode::Osu)[0].to_string(), "DT(1.3x)");
+        assert_eq!(GameModIntermediate::new("DT").to_string(), "DT");
+    }
+
+    #[test]
+    fn daycore_is_legacy_halftime() {
+        let mods = GameModsIntermediate::from_str("DC").unwrap();
+
+        assert!(mods.iter().all(GameModIntermediate::is_legacy));
+        assert_eq!(mods.legacy(), GameMods::HalfTime);
+        assert_eq!(mods.try_legacy(), Some(GameMods::HalfTime));
+    }
+
+    #[test]
+    fn typed_keeps_unknown_settings() {
+        let mut dt = GameModIntermediate::new("DT");
+        dt.settings
+            .insert("unknown".to_owned(), ModSettingValue::Bool(true));
+
+        assert_eq!(GameMod::new(&dt, GameMode::Osu), GameMod::Other(dt));
+    }
+}
@@ -158,38 +158,67 @@ macro_rules! def_enum {
 }
 
 mod cursor;
+mod game_mod;
 mod grade;
 mod mode;
 mod mods;
 mod serde_;
 
+#[cfg(any(feature = "osu-db", feature = "replay"))]
+mod binary;
+
 #[cfg(feature = "rkyv")]
 mod rkyv_impls;
 
+#[cfg(test)]
+mod test_util;
+
+pub(crate) mod analytics_;
 pub(crate) mod beatmap_;
+pub(crate) mod beatmap_attributes_;
 pub(crate) mod comments_;
 pub(crate) mod forum_;
 pub(crate) mod kudosu_;
 pub(crate) mod matches_;
 pub(crate) mod news_;
+#[cfg(feature = "osu-db")]
+pub(crate) mod osu_db_;
 pub(crate) mod ranking_;
 pub(crate) mod recent_event_;
+#[cfg(feature = "replay")]
+pub(crate) mod replay_;
+pub(crate) mod room_;
 pub(crate) mod score_;
+pub(crate) mod search_;
+pub(crate) mod search_query_;
 pub(crate) mod seasonal_backgrounds_;
+pub(crate) mod team_;
 pub(crate) mod user_;
 pub(crate) mod wiki_;
 
+/// Aggregates over scores such as weighted pp, bonus pp, or mod distributions
+pub mod analytics {
+    pub use super::analytics_::{
+        bonus_pp, bonus_pp_from_grades, Breakdown, ScoreAnalytics, MAX_BONUS_PP, WEIGHT_FACTOR,
+    };
+}
+
 /// Beatmap(set) related types
 pub mod beatmap {
     pub use super::beatmap_::{
-        Beatmap, BeatmapCompact, BeatmapDifficultyAttributes, Beatmapset, BeatmapsetAvailability,
+        Beatmap, BeatmapCompact, BeatmapDifficultyAttributes, BeatmapPack, BeatmapPackType,
+        BeatmapPackUserCompletion, BeatmapPacks, Beatmapset, BeatmapsetAvailability,
         BeatmapsetCommentEdit, BeatmapsetCommentId, BeatmapsetCommentKudosuGain,
         BeatmapsetCommentNominate, BeatmapsetCommentOwnerChange, BeatmapsetCompact,
-        BeatmapsetCovers, BeatmapsetDiscussion, BeatmapsetEvent, BeatmapsetEvents, BeatmapsetHype,
-        BeatmapsetNominations, BeatmapsetPost, BeatmapsetReviewsConfig, BeatmapsetSearchResult,
-        BeatmapsetSearchSort, BeatmapsetVote, FailTimes, GameModeAttributes, Genre, Language,
-        MostPlayedMap, RankStatus,
+        BeatmapsetCovers, BeatmapsetDiscussion, BeatmapsetEvent, BeatmapsetEventType,
+        BeatmapsetEvents, BeatmapsetHype, BeatmapsetNominations, BeatmapsetPost,
+        BeatmapsetReviewsConfig, BeatmapsetSearchContinuation, BeatmapsetSearchResult,
+        BeatmapsetSearchSort, BeatmapsetVote, CoverSize, FailTimes, GameModeAttributes, Genre,
+        Language, MostPlayedMap, RankStatus,
     };
+
+    pub use super::beatmap_attributes_::{BeatmapAttributes, HitWindows};
+    pub use super::search_query_::{SearchQuery, SearchRange, SearchTerm};
 }
 
 /// Comment related types
@@ -223,20 +252,46 @@ pub mod news {
 /// Ranking related types
 pub mod ranking {
     pub use super::ranking_::{
-        ChartRankings, CountryRanking, CountryRankings, Rankings, Spotlight,
+        ChartRankings, CountryRanking, CountryRankings, KudosuRankings, RankingFilter,
+        RankingVariant, Rankings, Spotlight, TeamRanking, TeamRankings,
     };
 }
 
 /// User event related types
 pub mod recent_event {
     pub use super::recent_event_::{
-        EventBeatmap, EventBeatmapset, EventType, EventUser, RecentEvent,
+        EventBeatmap, EventBeatmapset, EventSort, EventType, EventUser, Events, RecentEvent,
     };
 }
 
+/// Types of osu!stable's `collection.db` and `osu!.db` files
+#[cfg(feature = "osu-db")]
+pub mod osu_db {
+    pub use super::osu_db_::{BeatmapCollection, Collection, CollectionDb, OsuDb, OsuDbBeatmap};
+}
+
+/// Replay related types
+#[cfg(feature = "replay")]
+pub mod replay {
+    pub use super::replay_::{LazerReplayData, LifeBarFrame, Replay, ReplayFrame};
+}
+
+/// Multiplayer room related types
+pub mod room {
+    pub use super::room_::{PlaylistItem, Room, RoomCategory, RoomLeaderboard, RoomScore, Season};
+}
+
 /// Score related types
 pub mod score {
-    pub use super::score_::{BeatmapUserScore, Score, ScoreStatistics, ScoreWeight};
+    pub use super::score_::{
+        BeatmapScores, BeatmapUserScore, LazerScoreStatistics, LeaderboardType, Score,
+        ScoreStatistics, ScoreStatisticsBuilder, ScoreWeight,
+    };
+}
+
+/// Site search related types
+pub mod search {
+    pub use super::search_::{SearchHits, SearchMode, SearchResult};
 }
 
 /// Seasonal background related types
@@ -244,18 +299,26 @@ pub mod seasonal_backgrounds {
     pub use super::seasonal_backgrounds_::{SeasonalBackground, SeasonalBackgrounds};
 }
 
+/// Team related types
+pub mod team {
+    pub use super::team_::Team;
+}
+
 /// User related types
 pub mod user {
     pub use super::user_::{
-        AccountHistory, Badge, CountryCode, GradeCounts, Group, HistoryType, Medal, MedalCompact,
-        MonthlyCount, Playstyle, ProfileBanner, ProfilePage, User, UserCompact, UserCover,
-        UserHighestRank, UserKudosu, UserLevel, UserPage, UserStatistics, Username,
+        AccountHistory, Badge, CountryCode, DailyChallengeUserStats, GradeCounts, Group,
+        HistoryType, Medal, MedalCompact, MonthlyCount, Playstyle, ProfileBanner, ProfilePage,
+        User, UserCompact, UserCover, UserHighestRank, UserKudosu, UserLevel, UserPage,
+        UserStatistics, Username,
     };
 }
 
 /// Wiki related types
 pub mod wiki {
-    pub use super::wiki_::WikiPage;
+    pub use super::wiki_::{
+        WikiContent, WikiLink, WikiPage, WikiReference, WikiResponse, WikiSection,
+    };
 }
 
 /// Archived types and Resolvers for all types
@@ -263,20 +326,24 @@ pub mod wiki {
 pub mod rkyv {
     pub use super::beatmap_::{
         ArchivedBeatmap, ArchivedBeatmapCompact, ArchivedBeatmapDifficultyAttributes,
-        ArchivedBeatmapset, ArchivedBeatmapsetAvailability, ArchivedBeatmapsetCommentEdit,
-        ArchivedBeatmapsetCommentId, ArchivedBeatmapsetCommentKudosuGain,
-        ArchivedBeatmapsetCommentNominate, ArchivedBeatmapsetCommentOwnerChange,
-        ArchivedBeatmapsetCompact, ArchivedBeatmapsetCovers, ArchivedBeatmapsetDiscussion,
-        ArchivedBeatmapsetEvent, ArchivedBeatmapsetEvents, ArchivedBeatmapsetPost,
-        ArchivedFailTimes, ArchivedGameModeAttributes, ArchivedMostPlayedMap, ArchivedRankStatus,
-        BeatmapCompactResolver, BeatmapDifficultyAttributesResolver, BeatmapResolver,
-        BeatmapsetAvailabilityResolver, BeatmapsetCommentEditResolver, BeatmapsetCommentIdResolver,
-        BeatmapsetCommentKudosuGainResolver, BeatmapsetCommentNominateResolver,
-        BeatmapsetCommentOwnerChangeResolver, BeatmapsetCoversResolver,
-        BeatmapsetDiscussionResolver, BeatmapsetEventResolver, BeatmapsetEventsResolver,
-        BeatmapsetHypeResolver, BeatmapsetNominationsResolver, BeatmapsetPostResolver,
-        BeatmapsetResolver, BeatmapsetReviewsConfigResolver, BeatmapsetVoteResolver,
-        FailTimesResolver, GameModeAttributesResolver, MostPlayedMapResolver, RankStatusResolver,
+        ArchivedBeatmapPack, ArchivedBeatmapPackType, ArchivedBeatmapPackUserCompletion,
+        ArchivedBeatmapPacks, ArchivedBeatmapset, ArchivedBeatmapsetAvailability,
+        ArchivedBeatmapsetCommentEdit, ArchivedBeatmapsetCommentId,
+        ArchivedBeatmapsetCommentKudosuGain, ArchivedBeatmapsetCommentNominate,
+        ArchivedBeatmapsetCommentOwnerChange, ArchivedBeatmapsetCompact, ArchivedBeatmapsetCovers,
+        ArchivedBeatmapsetDiscussion, ArchivedBeatmapsetEvent, ArchivedBeatmapsetEventType,
+        ArchivedBeatmapsetEvents, ArchivedBeatmapsetPost, ArchivedFailTimes,
+        ArchivedGameModeAttributes, ArchivedMostPlayedMap, ArchivedRankStatus,
+        BeatmapCompactResolver, BeatmapDifficultyAttributesResolver, BeatmapPackResolver,
+        BeatmapPackTypeResolver, BeatmapPackUserCompletionResolver, BeatmapPacksResolver,
+        BeatmapResolver, BeatmapsetAvailabilityResolver, BeatmapsetCommentEditResolver,
+        BeatmapsetCommentIdResolver, BeatmapsetCommentKudosuGainResolver,
+        BeatmapsetCommentNominateResolver, BeatmapsetCommentOwnerChangeResolver,
+        BeatmapsetCoversResolver, BeatmapsetDiscussionResolver, BeatmapsetEventResolver,
+        BeatmapsetEventTypeResolver, BeatmapsetEventsResolver, BeatmapsetHypeResolver,
+        BeatmapsetNominationsResolver, BeatmapsetPostResolver, BeatmapsetResolver,
+        BeatmapsetReviewsConfigResolver, BeatmapsetVoteResolver, FailTimesResolver,
+        GameModeAttributesResolver, MostPlayedMapResolver, RankStatusResolver,
     };
 
     pub use super::comments_::{
@@ -289,6 +356,11 @@ pub mod rkyv {
         ForumPostsSearchResolver, ForumTopicResolver,
     };
 
+    pub use super::game_mod::{
+        ArchivedGameModIntermediate, ArchivedGameModsIntermediate, ArchivedModSettingValue,
+        GameModIntermediateResolver, GameModsIntermediateResolver, ModSettingValueResolver,
+    };
+
     pub use super::grade::{ArchivedGrade, GradeResolver};
 
     pub use super::kudosu_::{
@@ -303,43 +375,64 @@ pub mod rkyv {
     };
 
     pub use super::ranking_::{
-        ArchivedChartRankings, ArchivedCountryRanking, ArchivedCountryRankings, ArchivedRankings,
-        ArchivedSpotlight, ChartRankingsResolver, CountryRankingResolver, CountryRankingsResolver,
-        RankingsResolver, SpotlightResolver,
+        ArchivedChartRankings, ArchivedCountryRanking, ArchivedCountryRankings,
+        ArchivedKudosuRankings, ArchivedRankings, ArchivedSpotlight, ArchivedTeamRanking,
+        ArchivedTeamRankings, ChartRankingsResolver, CountryRankingResolver,
+        CountryRankingsResolver, KudosuRankingsResolver, RankingsResolver, SpotlightResolver,
+        TeamRankingResolver, TeamRankingsResolver,
     };
 
     pub use super::recent_event_::{
-        ArchivedEventBeatmap, ArchivedEventBeatmapset, ArchivedEventType, ArchivedEventUser,
-        ArchivedRecentEvent, EventBeatmapResolver, EventBeatmapsetResolver, EventTypeResolver,
-        EventUserResolver, RecentEventResolver,
+        ArchivedEventBeatmap, ArchivedEventBeatmapset, ArchivedEventSort, ArchivedEventType,
+        ArchivedEventUser, ArchivedEvents, ArchivedRecentEvent, EventBeatmapResolver,
+        EventBeatmapsetResolver, EventSortResolver, EventTypeResolver, EventUserResolver,
+        EventsResolver, RecentEventResolver,
     };
 
     pub use super::score_::{
-        ArchivedBeatmapUserScore, ArchivedScore, BeatmapUserScoreResolver, ScoreResolver,
+        ArchivedBeatmapScores, ArchivedBeatmapUserScore, ArchivedScore, BeatmapScoresResolver,
+        BeatmapUserScoreResolver, LazerScoreStatisticsResolver, ScoreResolver,
         ScoreStatisticsResolver, ScoreWeightResolver,
     };
 
+    pub use super::search_::{
+        ArchivedSearchHits, ArchivedSearchResult, SearchHitsResolver, SearchResultResolver,
+    };
+
+    pub use super::room_::{
+        ArchivedPlaylistItem, ArchivedRoom, ArchivedRoomCategory, ArchivedRoomLeaderboard,
+        ArchivedRoomScore, ArchivedSeason, PlaylistItemResolver, RoomCategoryResolver,
+        RoomLeaderboardResolver, RoomResolver, RoomScoreResolver, SeasonResolver,
+    };
+
     pub use super::seasonal_backgrounds_::{
         ArchivedSeasonalBackground, ArchivedSeasonalBackgrounds, SeasonalBackgroundResolver,
         SeasonalBackgroundsResolver,
     };
 
+    pub use super::team_::{ArchivedTeam, TeamResolver};
+
     pub use super::user_::{
-        AccountHistoryResolver, ArchivedAccountHistory, ArchivedBadge, ArchivedGroup,
-        ArchivedHistoryType, ArchivedMedal, ArchivedMedalCompact, ArchivedMonthlyCount,
-        ArchivedPlaystyle, ArchivedProfileBanner, ArchivedProfilePage, ArchivedUser,
-        ArchivedUserCompact, ArchivedUserCover, ArchivedUserHighestRank, ArchivedUserPage,
-        ArchivedUserStatistics, BadgeResolver, GradeCountsResolver, GroupResolver,
-        HistoryTypeResolver, MedalCompactResolver, MedalResolver, MonthlyCountResolver,
-        PlaystyleResolver, ProfileBannerResolver, ProfilePageResolver, UserCompactResolver,
-        UserCoverResolver, UserHighestRankResolver, UserKudosuResolver, UserLevelResolver,
-        UserPageResolver, UserResolver, UserStatisticsResolver,
+        AccountHistoryResolver, ArchivedAccountHistory, ArchivedBadge,
+        ArchivedDailyChallengeUserStats, ArchivedGroup, ArchivedHistoryType, ArchivedMedal,
+        ArchivedMedalCompact, ArchivedMonthlyCount, ArchivedPlaystyle, ArchivedProfileBanner,
+        ArchivedProfilePage, ArchivedUser, ArchivedUserCompact, ArchivedUserCover,
+        ArchivedUserHighestRank, ArchivedUserPage, ArchivedUserStatistics, BadgeResolver,
+        DailyChallengeUserStatsResolver, GradeCountsResolver, GroupResolver, HistoryTypeResolver,
+        MedalCompactResolver, MedalResolver, MonthlyCountResolver, PlaystyleResolver,
+        ProfileBannerResolver, ProfilePageResolver, UserCompactResolver, UserCoverResolver,
+        UserHighestRankResolver, UserKudosuResolver, UserLevelResolver, UserPageResolver,
+        UserResolver, UserStatisticsResolver,
     };
 
     pub use super::wiki_::{ArchivedWikiPage, WikiPageResolver};
 }
 
 pub use cursor::Cursor;
+pub use game_mod::{
+    Classic, DifficultyAdjust, GameMod, GameModIntermediate, GameModSettings, GameModsIntermediate,
+    ModSettingValue, RateAdjust,
+};
 pub use grade::Grade;
 pub use mode::GameMode;
 pub use mods::GameMods;
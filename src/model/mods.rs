@@ -522,7 +522,7 @@ impl Serialize for GameMods {
     }
 }
 
-mod util {
+pub(super) mod util {
     use std::borrow::Cow;
 
     /// Provide an iterator over substrings of the given length on the given source string
@@ -0,0 +1,582 @@
+use super::{
+    beatmap_::{Beatmap, Beatmapset},
+    binary::{ticks_to_datetime, write_string, Reader},
+    GameMode,
+};
+use crate::{error::OsuDbError, Osu, OsuResult};
+
+use std::collections::HashMap;
+use time::OffsetDateTime;
+
+/// Version from which on `osu!.db` stores difficulty values as floats
+/// and includes star ratings, but no unknown trailing short per beatmap
+const FLOAT_DIFFICULTY_VERSION: u32 = 20_140_609;
+
+/// Version from which on `osu!.db` no longer stores the size of each beatmap entry
+const NO_ENTRY_SIZE_VERSION: u32 = 20_191_106;
+
+/// Version from which on `osu!.db` stores star ratings as floats instead of doubles
+const FLOAT_STARS_VERSION: u32 = 20_250_107;
+
+/// Ranked status of a beatmap in `osu!.db` that was never submitted
+const UNSUBMITTED: u8 = 1;
+
+/// A parsed `collection.db` file, i.e. the collections of an osu!stable installation.
+///
+/// Use [`CollectionDb::from_bytes`] to parse the file and [`CollectionDb::import`]
+/// to retrieve the [`Beatmap`]s of each collection.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CollectionDb {
+    /// The version of the game that created the file, e.g. `20240123`
+    pub version: u32,
+    pub collections: Vec<Collection>,
+}
+
+/// A collection of a [`CollectionDb`]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Collection {
+    pub name: String,
+    /// MD5 hashes of the collection's beatmaps
+    pub checksums: Vec<String>,
+}
+
+/// A [`Collection`] whose checksums were resolved to [`Beatmap`]s
+#[derive(Clone, Debug, PartialEq)]
+pub struct BeatmapCollection {
+    pub name: String,
+    /// The collection's maps that are available on the osu! servers
+    pub maps: Vec<Beatmap>,
+}
+
+impl CollectionDb {
+    /// Parse the content of a `collection.db` file
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, OsuDbError> {
+        let mut reader = Reader { bytes };
+
+        let version = reader.u32()?;
+        let len = reader.u32()? as usize;
+        let mut collections = Vec::with_capacity(len.min(1024));
+
+        for _ in 0..len {
+            let name = reader.string()?.unwrap_or_default();
+            let len = reader.u32()? as usize;
+            let mut checksums = Vec::with_capacity(len.min(1024));
+
+            for _ in 0..len {
+                if let Some(checksum) = reader.string()? {
+                    checksums.push(checksum);
+                }
+            }
+
+            collections.push(Collection { name, checksums });
+        }
+
+        Ok(Self {
+            version,
+            collections,
+        })
+    }
+
+    /// Create the content of a `collection.db` file
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+
+        bytes.extend_from_slice(&self.version.to_le_bytes());
+        bytes.extend_from_slice(&(self.collections.len() as u32).to_le_bytes());
+
+        for collection in self.collections.iter() {
+            write_string(&mut bytes, Some(&collection.name));
+            bytes.extend_from_slice(&(collection.checksums.len() as u32).to_le_bytes());
+
+            for checksum in collection.checksums.iter() {
+                write_string(&mut bytes, Some(checksum));
+            }
+        }
+
+        bytes
+    }
+
+    /// Resolve the checksums of all collections to [`Beatmap`]s.
+    ///
+    /// Maps that are not available on the osu! servers are skipped.
+    /// Each unique checksum is requested once through
+    /// [`Osu::beatmaps_by_checksums`]. Errors if any of those requests failed.
+    pub async fn import(&self, osu: &Osu) -> OsuResult<Vec<BeatmapCollection>> {
+        let checksums = self
+            .collections
+            .iter()
+            .flat_map(|collection| collection.checksums.iter().map(String::as_str));
+
+        let maps = resolve_checksums(osu, checksums).await?;
+
+        let collections = self
+            .collections
+            .iter()
+            .map(|collection| BeatmapCollection {
+                name: collection.name.clone(),
+                maps: collection
+                    .checksums
+                    .iter()
+                    .filter_map(|checksum| maps.get(&checksum.to_ascii_lowercase()).cloned())
+                    .collect(),
+            })
+            .collect();
+
+        Ok(collections)
+    }
+}
+
+/// A parsed `osu!.db` file, i.e. the beatmaps of an osu!stable installation.
+///
+/// Use [`OsuDb::from_bytes`] to parse the file and [`OsuDb::import`]
+/// to retrieve the [`Beatmapset`]s of all submitted maps.
+#[derive(Clone, Debug, PartialEq)]
+pub struct OsuDb {
+    /// The version of the game that created the file, e.g. `20240123`
+    pub version: u32,
+    pub folder_count: u32,
+    pub account_unlocked: bool,
+    /// When the account will be unlocked
+    pub unlock_date: Option<OffsetDateTime>,
+    pub player_name: Option<String>,
+    pub maps: Vec<OsuDbBeatmap>,
+    /// Bitflags of the player's permissions, e.g. supporter
+    pub permissions: u32,
+}
+
+/// A beatmap entry of an [`OsuDb`].
+///
+/// Only the commonly used values of an entry are kept.
+#[derive(Clone, Debug, PartialEq)]
+pub struct OsuDbBeatmap {
+    pub artist: Option<String>,
+    pub artist_unicode: Option<String>,
+    pub title: Option<String>,
+    pub title_unicode: Option<String>,
+    pub creator: Option<String>,
+    /// The difficulty name
+    pub version: Option<String>,
+    pub audio_file: Option<String>,
+    /// MD5 hash of the beatmap
+    pub checksum: Option<String>,
+    /// Name of the `.osu` file
+    pub file_name: Option<String>,
+    /// Ranked status as stored by osu!stable, e.g. `1` for unsubmitted or `4` for ranked
+    pub ranked_status: u8,
+    pub count_circles: u16,
+    pub count_sliders: u16,
+    pub count_spinners: u16,
+    pub last_modified: Option<OffsetDateTime>,
+    pub ar: f32,
+    pub cs: f32,
+    pub hp: f32,
+    pub od: f32,
+    pub slider_velocity: f64,
+    pub seconds_drain: u32,
+    /// Total length in milliseconds
+    pub ms_total: u32,
+    /// Start of the audio preview in milliseconds
+    pub preview_time: u32,
+    /// Zero if the map was never submitted
+    pub map_id: u32,
+    /// Zero if the map was never submitted
+    pub mapset_id: u32,
+    pub mode: GameMode,
+    pub source: Option<String>,
+    pub tags: Option<String>,
+    pub unplayed: bool,
+    pub last_played: Option<OffsetDateTime>,
+    /// Name of the folder within the `Songs` directory
+    pub folder_name: Option<String>,
+}
+
+impl OsuDbBeatmap {
+    /// Whether the map was ever submitted to the osu! servers
+    #[inline]
+    pub fn is_submitted(&self) -> bool {
+        self.map_id > 0 && self.ranked_status != UNSUBMITTED && self.checksum.is_some()
+    }
+}
+
+impl OsuDb {
+    /// Parse the content of an `osu!.db` file
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, OsuDbError> {
+        let mut reader = Reader { bytes };
+
+        let version = reader.u32()?;
+        let folder_count = reader.u32()?;
+        let account_unlocked = reader.bool()?;
+        let unlock_date = read_datetime(&mut reader)?;
+        let player_name = reader.string()?;
+
+        let len = reader.u32()? as usize;
+        let mut maps = Vec::with_capacity(len.min(65_536));
+
+        for _ in 0..len {
+            maps.push(read_beatmap(&mut reader, version)?);
+        }
+
+        let permissions = reader.u32()?;
+
+        Ok(Self {
+            version,
+            folder_count,
+            account_unlocked,
+            unlock_date,
+            player_name,
+            maps,
+            permissions,
+        })
+    }
+
+    /// Resolve all submitted maps to [`Beatmap`]s, grouped into their [`Beatmapset`]s.
+    ///
+    /// Maps that were never submitted or are no longer available on the osu! servers
+    /// are skipped. Each unique checksum is requested once through
+    /// [`Osu::beatmaps_by_checksums`]. Errors if any of those requests failed.
+    ///
+    /// The mapsets are ordered by their first map in the file
+    /// and their `maps` option is filled with the imported maps.
+    pub async fn import(&self, osu: &Osu) -> OsuResult<Vec<Beatmapset>> {
+        let checksums = self
+            .maps
+            .iter()
+            .filter(|map| map.is_submitted())
+            .filter_map(|map| map.checksum.as_deref());
+
+        let mut maps = resolve_checksums(osu, checksums).await?;
+
+        let mut mapsets: Vec<Beatmapset> = Vec::new();
+        let mut indices: HashMap<u32, usize> = HashMap::new();
+
+        for entry in self.maps.iter().filter(|map| map.is_submitted()) {
+            let mut map = match entry
+                .checksum
+                .as_ref()
+                .and_then(|checksum| maps.remove(&checksum.to_ascii_lowercase()))
+            {
+                Some(map) => map,
+                None => continue,
+            };
+
+            if let Some(&idx) = indices.get(&map.mapset_id) {
+                let mapset = &mut mapsets[idx];
+                mapset.maps.get_or_insert_with(Vec::new).push(map);
+            } else if let Some(mut mapset) = map.mapset.take() {
+                // The mapset is always included by the checksum lookup
+                indices.insert(map.mapset_id, mapsets.len());
+                mapset.maps = Some(vec![map]);
+                mapsets.push(mapset);
+            }
+        }
+
+        Ok(mapsets)
+    }
+}
+
+/// Request the maps of all checksums, skipping those without a map
+async fn resolve_checksums<'c, I>(osu: &Osu, checksums: I) -> OsuResult<HashMap<String, Beatmap>>
+where
+    I: IntoIterator<Item = &'c str>,
+{
+    let mut maps = HashMap::new();
+
+    for (checksum, map) in osu.beatmaps_by_checksums(checksums).await? {
+        if let Some(map) = map? {
+            maps.insert(checksum, map);
+        }
+    }
+
+    Ok(maps)
+}
+
+fn read_datetime(reader: &mut Reader<'_>) -> Result<Option<OffsetDateTime>, OsuDbError> {
+    match reader.i64()? {
+        0 => Ok(None),
+        ticks => ticks_to_datetime(ticks)
+            .map(Some)
+            .ok_or(OsuDbError::Timestamp(ticks)),
+    }
+}
+
+fn read_beatmap(reader: &mut Reader<'_>, version: u32) -> Result<OsuDbBeatmap, OsuDbError> {
+    if version < NO_ENTRY_SIZE_VERSION {
+        reader.u32()?;
+    }
+
+    let artist = reader.string()?;
+    let artist_unicode = reader.string()?;
+    let title = reader.string()?;
+    let title_unicode = reader.string()?;
+    let creator = reader.string()?;
+    let difficulty = reader.string()?;
+    let audio_file = reader.string()?;
+    let checksum = reader.string()?;
+    let file_name = reader.string()?;
+    let ranked_status = reader.u8()?;
+    let count_circles = reader.u16()?;
+    let count_sliders = reader.u16()?;
+    let count_spinners = reader.u16()?;
+    let last_modified = read_datetime(reader)?;
+
+    let mut difficulty_value = || {
+        if version < FLOAT_DIFFICULTY_VERSION {
+            reader.u8().map(f32::from)
+        } else {
+            reader.f32()
+        }
+    };
+
+    let ar = difficulty_value()?;
+    let cs = difficulty_value()?;
+    let hp = difficulty_value()?;
+    let od = difficulty_value()?;
+    let slider_velocity = reader.f64()?;
+
+    if version >= FLOAT_DIFFICULTY_VERSION {
+        // Star ratings of each mode for various mod combinations:
+        // byte prefix, int mods, byte prefix, double (or float) stars
+        let pair_len = if version >= FLOAT_STARS_VERSION {
+            10
+        } else {
+            14
+        };
+
+        for _ in 0..4 {
+            let len = reader.u32()? as usize;
+            reader.take(len * pair_len)?;
+        }
+    }
+
+    let seconds_drain = reader.u32()?;
+    let ms_total = reader.u32()?;
+    let preview_time = reader.u32()?;
+
+    // Timing points: double bpm, double offset, bool inherited
+    let len = reader.u32()? as usize;
+    reader.take(len * 17)?;
+
+    let map_id = reader.u32()?;
+    let mapset_id = reader.u32()?;
+
+    // Thread id, grades of each mode, local offset
+    reader.take(4 + 4 + 2)?;
+
+    // Stack leniency
+    reader.f32()?;
+
+    let mode = match reader.u8()? {
+        mode @ 0..=3 => GameMode::from(mode),
+        mode => return Err(OsuDbError::Mode(mode)),
+    };
+
+    let source = reader.string()?;
+    let tags = reader.string()?;
+
+    // Online offset
+    reader.u16()?;
+
+    // Font of the title
+    reader.string()?;
+
+    let unplayed = reader.bool()?;
+    let last_played = read_datetime(reader)?;
+
+    // Whether the map is an osz2
+    reader.bool()?;
+
+    let folder_name = reader.string()?;
+
+    // Last check against the osu! servers, ignore sound, ignore skin,
+    // disable storyboard, disable video, visual override
+    reader.take(8 + 5)?;
+
+    if version < FLOAT_DIFFICULTY_VERSION {
+        reader.u16()?;
+    }
+
+    // Last modification time, mania scroll speed
+    reader.take(4 + 1)?;
+
+    Ok(OsuDbBeatmap {
+        artist,
+        artist_unicode,
+        title,
+        title_unicode,
+        creator,
+        version: difficulty,
+        audio_file,
+        checksum,
+        file_name,
+        ranked_status,
+        count_circles,
+        count_sliders,
+        count_spinners,
+        last_modified,
+        ar,
+        cs,
+        hp,
+        od,
+        slider_velocity,
+        seconds_drain,
+        ms_total,
+        preview_time,
+        map_id,
+        mapset_id,
+        mode,
+        source,
+        tags,
+        unplayed,
+        last_played,
+        folder_name,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::binary::datetime_to_ticks;
+
+    fn write_datetime(bytes: &mut Vec<u8>, datetime: Option<OffsetDateTime>) {
+        let ticks = datetime.map_or(0, datetime_to_ticks);
+        bytes.extend_from_slice(&ticks.to_le_bytes());
+    }
+
+    fn osu_db_bytes(version: u32) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&version.to_le_bytes());
+        bytes.extend_from_slice(&3_u32.to_le_bytes());
+        bytes.push(1);
+        write_datetime(&mut bytes, None);
+        write_string(&mut bytes, Some("player"));
+        bytes.extend_from_slice(&1_u32.to_le_bytes());
+
+        let mut entry = Vec::new();
+
+        for s in [
+            "artist",
+            "artist",
+            "title",
+            "title",
+            "creator",
+            "Insane",
+            "audio.mp3",
+        ] {
+            write_string(&mut entry, Some(s));
+        }
+
+        write_string(&mut entry, Some("d41d8cd98f00b204e9800998ecf8427e"));
+        write_string(&mut entry, Some("map.osu"));
+        entry.push(4);
+        entry.extend_from_slice(&[100, 0, 50, 0, 1, 0]);
+        write_datetime(&mut entry, Some(OffsetDateTime::UNIX_EPOCH));
+        entry.extend_from_slice(&9.0_f32.to_le_bytes());
+        entry.extend_from_slice(&4.0_f32.to_le_bytes());
+        entry.extend_from_slice(&6.0_f32.to_le_bytes());
+        entry.extend_from_slice(&8.0_f32.to_le_bytes());
+        entry.extend_from_slice(&1.4_f64.to_le_bytes());
+
+        // One star rating for osu!standard, none for the other modes
+        entry.extend_from_slice(&1_u32.to_le_bytes());
+        entry.push(0x08);
+        entry.extend_from_slice(&0_u32.to_le_bytes());
+
+        if version >= FLOAT_STARS_VERSION {
+            entry.push(0x0C);
+            entry.extend_from_slice(&5.5_f32.to_le_bytes());
+        } else {
+            entry.push(0x0D);
+            entry.extend_from_slice(&5.5_f64.to_le_bytes());
+        }
+
+        entry.extend_from_slice(&[0; 12]);
+        entry.extend_from_slice(&90_u32.to_le_bytes());
+        entry.extend_from_slice(&100_000_u32.to_le_bytes());
+        entry.extend_from_slice(&30_000_u32.to_le_bytes());
+
+        // One timing point
+        entry.extend_from_slice(&1_u32.to_le_bytes());
+        entry.extend_from_slice(&[0; 17]);
+
+        entry.extend_from_slice(&123_u32.to_le_bytes());
+        entry.extend_from_slice(&45_u32.to_le_bytes());
+        entry.extend_from_slice(&[0; 10]);
+        entry.extend_from_slice(&0.7_f32.to_le_bytes());
+        entry.push(0);
+        write_string(&mut entry, Some("source"));
+        write_string(&mut entry, Some("tag1 tag2"));
+        entry.extend_from_slice(&[0; 2]);
+        write_string(&mut entry, None);
+        entry.push(0);
+        write_datetime(&mut entry, None);
+        entry.push(0);
+        write_string(&mut entry, Some("45 artist - title"));
+        entry.extend_from_slice(&[0; 13 + 4 + 1]);
+
+        if version < NO_ENTRY_SIZE_VERSION {
+            bytes.extend_from_slice(&(entry.len() as u32).to_le_bytes());
+        }
+
+        bytes.extend_from_slice(&entry);
+        bytes.extend_from_slice(&4_u32.to_le_bytes());
+
+        bytes
+    }
+
+    #[test]
+    fn collection_db_roundtrip() {
+        let db = CollectionDb {
+            version: 20_240_123,
+            collections: vec![
+                Collection {
+                    name: "farm".to_owned(),
+                    checksums: vec![
+                        "d41d8cd98f00b204e9800998ecf8427e".to_owned(),
+                        "0cc175b9c0f1b6a831c399e269772661".to_owned(),
+                    ],
+                },
+                Collection {
+                    name: "empty".to_owned(),
+                    checksums: Vec::new(),
+                },
+            ],
+        };
+
+        let bytes = db.to_bytes();
+        assert_eq!(CollectionDb::from_bytes(&bytes).unwrap(), db);
+    }
+
+    #[test]
+    fn osu_db() {
+        for version in [20_191_105, 20_240_123, 20_250_107] {
+            let db = OsuDb::from_bytes(&osu_db_bytes(version)).unwrap();
+
+            assert_eq!(db.player_name.as_deref(), Some("player"));
+            assert_eq!(db.permissions, 4);
+            assert_eq!(db.maps.len(), 1);
+
+            let map = &db.maps[0];
+            assert_eq!(map.version.as_deref(), Some("Insane"));
+            assert_eq!(map.count_circles, 100);
+            assert_eq!(map.last_modified, Some(OffsetDateTime::UNIX_EPOCH));
+            assert_eq!(map.ar, 9.0);
+            assert_eq!(map.seconds_drain, 90);
+            assert_eq!(map.map_id, 123);
+            assert_eq!(map.mapset_id, 45);
+            assert_eq!(map.mode, GameMode::Osu);
+            assert_eq!(map.tags.as_deref(), Some("tag1 tag2"));
+            assert_eq!(map.folder_name.as_deref(), Some("45 artist - title"));
+            assert!(map.is_submitted());
+        }
+    }
+
+    #[test]
+    fn osu_db_truncated() {
+        let bytes = osu_db_bytes(20_240_123);
+
+        assert!(matches!(
+            OsuDb::from_bytes(&bytes[..bytes.len() - 10]),
+            Err(OsuDbError::UnexpectedEof)
+        ));
+    }
+}
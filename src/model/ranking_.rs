@@ -1,9 +1,10 @@
 use super::{
     beatmap::Beatmapset,
     serde_,
+    team_::Team,
     user_::{
         deserialize_country, AccountHistory, Badge, Group, MedalCompact, MonthlyCount, UserCompact,
-        UserCover, UserPage, UserStatistics,
+        UserCover, UserKudosu, UserPage, UserStatistics,
     },
     GameMode,
 };
@@ -12,6 +13,9 @@ use crate::{
     Osu, OsuResult,
 };
 
+#[cfg(not(feature = "rkyv"))]
+use crate::request::{GetPerformanceRankings, GetScoreRankings, RankingParams};
+
 use serde::{
     de::{Deserializer, Error, IgnoredAny, MapAccess, SeqAccess, Visitor},
     ser::{SerializeSeq, SerializeStruct, Serializer},
@@ -86,6 +90,75 @@ impl CountryRankings {
     }
 }
 
+/// A page of the kudosu leaderboard
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
+pub struct KudosuRankings {
+    /// The next page of the ranking
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_page: Option<u32>,
+    /// User details ordered by total kudosu in descending order.
+    /// The `kudosu` option of each user is filled.
+    pub ranking: Vec<UserCompact>,
+}
+
+impl KudosuRankings {
+    /// If `next_page` is `Some`, the API can provide the next set of users and this method will request them.
+    /// Otherwise, this method returns `None`.
+    #[inline]
+    pub async fn get_next(&self, osu: &Osu) -> Option<OsuResult<KudosuRankings>> {
+        Some(osu.kudosu_rankings().page(self.next_page?).await)
+    }
+}
+
+/// A team's entry in the team leaderboard of a mode
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
+pub struct TeamRanking {
+    /// Amount of members of the team
+    pub members_count: u32,
+    /// The mode of the leaderboard
+    #[serde(rename = "ruleset_id")]
+    pub mode: GameMode,
+    /// Summed playcount for all members
+    #[serde(rename = "play_count")]
+    pub playcount: u64,
+    /// Summed performance points for all members
+    #[serde(rename = "performance")]
+    pub pp: f32,
+    /// Summed ranked score for all members
+    pub ranked_score: u64,
+    /// The team itself
+    pub team: Team,
+}
+
+/// A page of the team leaderboard of a mode
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
+pub struct TeamRankings {
+    /// The next page of the ranking
+    #[serde(
+        default,
+        rename = "cursor",
+        deserialize_with = "deserialize_rankings_cursor",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub next_page: Option<u32>,
+    /// Team details ordered by pp in descending order.
+    pub ranking: Vec<TeamRanking>,
+    /// Total amount of teams
+    pub total: u32,
+}
+
+impl TeamRankings {
+    /// If `next_page` is `Some`, the API can provide the next set of teams and this method will request them.
+    /// Otherwise, this method returns `None`.
+    #[inline]
+    pub async fn get_next(&self, osu: &Osu, mode: GameMode) -> Option<OsuResult<TeamRankings>> {
+        Some(osu.team_rankings(mode).page(self.next_page?).await)
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 #[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
 pub struct Rankings {
@@ -106,6 +179,9 @@ pub struct Rankings {
     #[serde(default)]
     #[cfg(not(feature = "rkyv"))]
     pub(crate) ranking_type: Option<RankingType>,
+    #[serde(skip)]
+    #[cfg(not(feature = "rkyv"))]
+    pub(crate) params: RankingParams,
     pub total: u32,
 }
 
@@ -348,6 +424,8 @@ struct UserCompactWithoutStats<'u> {
     pub is_nat: &'u Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub is_silenced: &'u Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kudosu: &'u Option<UserKudosu>,
     #[serde(
         rename = "loved_beatmapset_count",
         skip_serializing_if = "Option::is_none"
@@ -378,6 +456,8 @@ struct UserCompactWithoutStats<'u> {
     pub scores_recent_count: &'u Option<u32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub support_level: &'u Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub team: &'u Option<Team>,
     #[serde(
         rename = "pending_beatmapset_count",
         skip_serializing_if = "Option::is_none"
@@ -420,6 +500,7 @@ impl<'u> UserCompactWithoutStats<'u> {
             is_moderator,
             is_nat,
             is_silenced,
+            kudosu,
             loved_mapset_count,
             medals,
             monthly_playcounts,
@@ -433,6 +514,7 @@ impl<'u> UserCompactWithoutStats<'u> {
             scores_recent_count,
             statistics: _,
             support_level,
+            team,
             pending_mapset_count,
         } = user;
 
@@ -469,6 +551,7 @@ impl<'u> UserCompactWithoutStats<'u> {
             is_moderator,
             is_nat,
             is_silenced,
+            kudosu,
             loved_mapset_count,
             medals,
             monthly_playcounts,
@@ -481,6 +564,7 @@ impl<'u> UserCompactWithoutStats<'u> {
             scores_first_count,
             scores_recent_count,
             support_level,
+            team,
             pending_mapset_count,
         }
     }
@@ -504,6 +588,7 @@ pub(crate) enum RankingType {
     Country,
     Performance,
     Score,
+    Team,
 }
 
 impl fmt::Display for RankingType {
@@ -514,12 +599,65 @@ impl fmt::Display for RankingType {
             Self::Country => "country",
             Self::Performance => "performance",
             Self::Score => "score",
+            Self::Team => "team",
         };
 
         f.write_str(kind)
     }
 }
 
+/// Restrict the users of a ranking
+#[derive(Copy, Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RankingFilter {
+    /// Include all users
+    All,
+    /// Only include friends of the authenticated user
+    Friends,
+}
+
+impl Default for RankingFilter {
+    #[inline]
+    fn default() -> Self {
+        Self::All
+    }
+}
+
+impl fmt::Display for RankingFilter {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let filter = match self {
+            Self::All => "all",
+            Self::Friends => "friends",
+        };
+
+        f.write_str(filter)
+    }
+}
+
+/// Key variant of an osu!mania ranking
+#[derive(Copy, Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum RankingVariant {
+    /// Only consider 4K scores
+    #[serde(rename = "4k")]
+    FourKey,
+    /// Only consider 7K scores
+    #[serde(rename = "7k")]
+    SevenKey,
+}
+
+impl fmt::Display for RankingVariant {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let variant = match self {
+            Self::FourKey => "4k",
+            Self::SevenKey => "7k",
+        };
+
+        f.write_str(variant)
+    }
+}
+
 impl Rankings {
     /// If `next_page` is `Some`, the API can provide the next set of users and this method will request them.
     /// Otherwise, this method returns `None`.
@@ -530,10 +668,13 @@ impl Rankings {
         let mode = self.mode?;
         let kind = self.ranking_type?;
 
+        let mut params = self.params.clone();
+        params.page = Some(page);
+
         let rankings = match kind {
-            RankingType::Performance => osu.performance_rankings(mode).page(page).await,
-            RankingType::Score => osu.score_rankings(mode).page(page).await,
-            RankingType::Charts | RankingType::Country => unreachable!(),
+            RankingType::Performance => GetPerformanceRankings::new(osu, mode).params(params).await,
+            RankingType::Score => GetScoreRankings::new(osu, mode).params(params).await,
+            RankingType::Charts | RankingType::Country | RankingType::Team => unreachable!(),
         };
 
         Some(rankings)
@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use std::fmt;
 use time::OffsetDateTime;
 
 use super::{
@@ -8,11 +9,17 @@ use super::{
     GameMode, Grade,
 };
 
+#[cfg(not(feature = "rkyv"))]
+use {
+    super::Cursor,
+    crate::{Osu, OsuResult},
+};
+
 #[cfg(feature = "rkyv")]
 use rkyv::{Archive, Deserialize as RkyvDeserialize, Serialize as RkyvSerialize};
 
 /// The object has different attributes depending on its type.
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 #[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
 pub struct RecentEvent {
     #[serde(with = "serde_::datetime")]
@@ -24,21 +31,89 @@ pub struct RecentEvent {
     pub event_type: EventType,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+/// A page of site-wide [`RecentEvent`]s
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
+pub struct Events {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg(not(feature = "rkyv"))]
+    pub(crate) cursor: Option<Cursor>,
+    pub events: Vec<RecentEvent>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) sort: Option<EventSort>,
+}
+
+impl Events {
+    /// Returns whether there is a next page of events,
+    /// retrievable via [`get_next`](Events::get_next).
+    #[inline]
+    #[cfg(not(feature = "rkyv"))]
+    pub fn has_more(&self) -> bool {
+        self.cursor.is_some()
+    }
+
+    /// If [`has_more`](Events::has_more) is true, the API can provide the next set of events and this method will request them.
+    /// Otherwise, this method returns `None`.
+    #[inline]
+    #[cfg(not(feature = "rkyv"))]
+    pub async fn get_next(&self, osu: &Osu) -> Option<OsuResult<Events>> {
+        let cursor = self.cursor.clone()?;
+        let mut fut = osu.events().cursor(cursor);
+
+        if let Some(sort) = self.sort {
+            fut = fut.sort(sort);
+        }
+
+        Some(fut.await)
+    }
+}
+
+/// Available orders for [`Events`] and [`BeatmapsetEvents`](crate::model::beatmap::BeatmapsetEvents)
+#[derive(Copy, Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
+pub enum EventSort {
+    /// Sort by id, newest first
+    #[serde(rename = "id_desc")]
+    IdDescending,
+    /// Sort by id, oldest first
+    #[serde(rename = "id_asc")]
+    IdAscending,
+}
+
+impl Default for EventSort {
+    #[inline]
+    fn default() -> Self {
+        Self::IdDescending
+    }
+}
+
+impl fmt::Display for EventSort {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sort = match self {
+            Self::IdDescending => "id_desc",
+            Self::IdAscending => "id_asc",
+        };
+
+        f.write_str(sort)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 #[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
 pub struct EventBeatmap {
     pub title: String,
     pub url: String,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 #[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
 pub struct EventBeatmapset {
     pub title: String,
     pub url: String,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 #[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
 #[serde(rename_all = "camelCase", tag = "type")]
 pub enum EventType {
@@ -112,7 +187,7 @@ pub enum EventType {
     },
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 #[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
 pub struct EventUser {
     #[cfg_attr(feature = "rkyv", with(super::rkyv_impls::UsernameWrapper))]
@@ -127,3 +202,73 @@ pub struct EventUser {
     #[cfg_attr(feature = "rkyv", with(super::rkyv_impls::UsernameMap))]
     pub previous_username: Option<Username>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::de::DeserializeOwned;
+    use std::fmt::Debug;
+
+    fn ser_de<T: DeserializeOwned + Serialize + PartialEq + Debug>(val: &T) {
+        let serialized =
+            serde_json::to_string(val).unwrap_or_else(|e| panic!("Failed to serialize: {}", e));
+
+        let deserialized: T = serde_json::from_str(&serialized)
+            .unwrap_or_else(|e| panic!("Failed to deserialize: {}", e));
+
+        assert_eq!(val, &deserialized);
+    }
+
+    fn events() -> Events {
+        let user = EventUser {
+            username: "Badewanne3".into(),
+            url: "/u/2211396".to_owned(),
+            previous_username: Some("Badewanne2".into()),
+        };
+
+        Events {
+            #[cfg(not(feature = "rkyv"))]
+            cursor: Some(Cursor::new(serde_json::json!({ "event_id": 42 }))),
+            events: vec![
+                RecentEvent {
+                    created_at: OffsetDateTime::from_unix_timestamp(1_600_000_000).unwrap(),
+                    event_id: 42,
+                    event_type: EventType::Rank {
+                        grade: Grade::S,
+                        rank: 1,
+                        mode: GameMode::Taiko,
+                        beatmap: EventBeatmap {
+                            title: "Artist - Title [Oni]".to_owned(),
+                            url: "/b/123".to_owned(),
+                        },
+                        user: user.clone(),
+                    },
+                },
+                RecentEvent {
+                    created_at: OffsetDateTime::from_unix_timestamp(1_600_000_100).unwrap(),
+                    event_id: 43,
+                    event_type: EventType::UsernameChange { user },
+                },
+            ],
+            sort: Some(EventSort::IdAscending),
+        }
+    }
+
+    #[test]
+    fn ser_de_events() {
+        ser_de(&events());
+    }
+
+    #[cfg(feature = "rkyv")]
+    #[test]
+    fn rkyv_events() {
+        use rkyv::{archived_root, Infallible};
+
+        let events = events();
+        let bytes = rkyv::to_bytes::<_, 512>(&events).unwrap();
+        let archived = unsafe { archived_root::<Events>(&bytes) };
+        let deserialized: Events = archived.deserialize(&mut Infallible).unwrap();
+
+        assert_eq!(events, deserialized);
+    }
+}
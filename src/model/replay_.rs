@@ -0,0 +1,553 @@
+use super::{
+    binary::{datetime_to_ticks, ticks_to_datetime, write_string, Reader},
+    game_mod::GameModsIntermediate,
+    score_::{LazerScoreStatistics, Score},
+    GameMode, GameMods,
+};
+use crate::error::ReplayError;
+
+use lzma_rs::compress::{Options, UnpackedSize};
+use serde::{Deserialize, Serialize};
+use std::{
+    convert::TryFrom,
+    fmt::{Result as FmtResult, Write},
+    io::Cursor,
+    str::FromStr,
+};
+use time::OffsetDateTime;
+
+/// Legacy mod bit of the target practice mod which implies additional data in a replay
+const TARGET_PRACTICE: u32 = 1 << 23;
+
+/// Delta of the frame that contains the seed of the random number generator
+const RNG_SEED_DELTA: i32 = -12345;
+
+/// A parsed `.osr` file, i.e. the data of a replay.
+///
+/// Use [`Replay::from_bytes`] to parse the bytes of e.g.
+/// [`GetReplayRaw`](crate::request::GetReplayRaw) and [`Replay::to_bytes`]
+/// to create a `.osr` file.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Replay {
+    pub mode: GameMode,
+    /// The version of the game that created the replay, e.g. `20240123`
+    pub version: u32,
+    /// MD5 hash of the beatmap
+    pub map_hash: Option<String>,
+    pub player_name: Option<String>,
+    /// MD5 hash of the replay
+    pub replay_hash: Option<String>,
+    pub count_300: u16,
+    pub count_100: u16,
+    pub count_50: u16,
+    pub count_geki: u16,
+    pub count_katu: u16,
+    pub count_miss: u16,
+    pub score: u32,
+    pub max_combo: u16,
+    pub perfect: bool,
+    /// Bits of the legacy mods, see [`Replay::game_mods`]
+    pub mods: u32,
+    pub life_bar: Vec<LifeBarFrame>,
+    pub timestamp: OffsetDateTime,
+    /// The cursor and key states of the play
+    pub frames: Vec<ReplayFrame>,
+    /// Seed of the random number generator, stored in the last frame by osu!stable
+    pub rng_seed: Option<u32>,
+    pub score_id: u64,
+    /// Accuracy of all hits, only available for replays with the target practice mod
+    pub target_practice_accuracy: Option<f64>,
+    /// Additional data that the lazer client stores in its replays
+    pub lazer: Option<LazerReplayData>,
+}
+
+/// The health of a player at some point during a [`Replay`]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct LifeBarFrame {
+    /// Time in milliseconds since the start of the beatmap
+    pub time: i32,
+    /// Health between 0 and 1
+    pub hp: f32,
+}
+
+/// The cursor and key state of a [`Replay`] at some point in time
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ReplayFrame {
+    /// Time in milliseconds since the previous frame
+    pub delta: i32,
+    /// Cursor position, or in case of [`GameMode::Mania`] the pressed keys as bits
+    pub x: f32,
+    /// Cursor position
+    pub y: f32,
+    /// Bits of the pressed keys, depends on the mode
+    pub keys: u32,
+}
+
+/// Score data that the lazer client appends to the end of its replays
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct LazerReplayData {
+    #[serde(default, rename = "online_id", skip_serializing_if = "Option::is_none")]
+    pub score_id: Option<i64>,
+    #[serde(default)]
+    pub mods: GameModsIntermediate,
+    #[serde(default)]
+    pub statistics: LazerScoreStatistics,
+    #[serde(default)]
+    pub maximum_statistics: LazerScoreStatistics,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_version: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub total_score_without_mods: Option<u64>,
+}
+
+impl Replay {
+    /// The first version of the lazer client, replays of this version or later
+    /// may contain [`LazerReplayData`]
+    pub const FIRST_LAZER_VERSION: u32 = 30_000_001;
+
+    /// The version that [`Replay::from_score`] uses
+    pub const LATEST_VERSION: u32 = 30_000_016;
+
+    /// Parse the content of a `.osr` file
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, ReplayError> {
+        let mut reader = Reader { bytes };
+
+        let mode = match reader.u8()? {
+            mode @ 0..=3 => GameMode::from(mode),
+            mode => return Err(ReplayError::Mode(mode)),
+        };
+
+        let version = reader.u32()?;
+        let map_hash = reader.string()?;
+        let player_name = reader.string()?;
+        let replay_hash = reader.string()?;
+        let count_300 = reader.u16()?;
+        let count_100 = reader.u16()?;
+        let count_50 = reader.u16()?;
+        let count_geki = reader.u16()?;
+        let count_katu = reader.u16()?;
+        let count_miss = reader.u16()?;
+        let score = reader.u32()?;
+        let max_combo = reader.u16()?;
+        let perfect = reader.u8()? != 0;
+        let mods = reader.u32()?;
+
+        let life_bar = reader
+            .string()?
+            .as_deref()
+            .map(parse_life_bar)
+            .transpose()?
+            .unwrap_or_default();
+
+        let ticks = reader.i64()?;
+        let timestamp = ticks_to_datetime(ticks).ok_or(ReplayError::Timestamp(ticks))?;
+
+        let len = reader.u32()? as usize;
+        let compressed = reader.take(len)?;
+
+        let mut frames = if compressed.is_empty() {
+            Vec::new()
+        } else {
+            parse_frames(&decompress(compressed)?)?
+        };
+
+        let rng_seed = match frames.last() {
+            Some(frame) if frame.delta == RNG_SEED_DELTA => frames.pop().map(|frame| frame.keys),
+            _ => None,
+        };
+
+        let score_id = reader.u64()?;
+
+        let target_practice_accuracy = if mods & TARGET_PRACTICE > 0 {
+            Some(reader.f64()?)
+        } else {
+            None
+        };
+
+        let lazer = if version >= Self::FIRST_LAZER_VERSION && !reader.bytes.is_empty() {
+            let len = reader.u32()? as usize;
+            let json = decompress(reader.take(len)?)?;
+
+            Some(serde_json::from_slice(&json).map_err(ReplayError::LazerData)?)
+        } else {
+            None
+        };
+
+        Ok(Self {
+            mode,
+            version,
+            map_hash,
+            player_name,
+            replay_hash,
+            count_300,
+            count_100,
+            count_50,
+            count_geki,
+            count_katu,
+            count_miss,
+            score,
+            max_combo,
+            perfect,
+            mods,
+            life_bar,
+            timestamp,
+            frames,
+            rng_seed,
+            score_id,
+            target_practice_accuracy,
+            lazer,
+        })
+    }
+
+    /// Create a replay of the score with the given frames.
+    ///
+    /// The replay contains [`LazerReplayData`] based on the score's statistics.
+    /// Since the API does not provide it, the life bar is empty.
+    pub fn from_score(score: &Score, frames: Vec<ReplayFrame>) -> Self {
+        let stats = score.statistics();
+        let count = |n: u32| u16::try_from(n).unwrap_or(u16::MAX);
+
+        let lazer = LazerReplayData {
+            score_id: score.score_id.map(|id| id as i64),
+            mods: score.mods.clone(),
+            statistics: score.lazer_statistics.clone(),
+            maximum_statistics: score.maximum_statistics.clone().unwrap_or_default(),
+            client_version: None,
+            user_id: Some(score.user_id),
+            total_score_without_mods: None,
+        };
+
+        Self {
+            mode: score.mode,
+            version: Self::LATEST_VERSION,
+            map_hash: score.map.as_ref().and_then(|map| map.checksum.clone()),
+            player_name: score.user.as_ref().map(|user| user.username.to_string()),
+            replay_hash: None,
+            count_300: count(stats.count_300),
+            count_100: count(stats.count_100),
+            count_50: count(stats.count_50),
+            count_geki: count(stats.count_geki),
+            count_katu: count(stats.count_katu),
+            count_miss: count(stats.count_miss),
+            score: score.score,
+            max_combo: count(score.max_combo),
+            perfect: score.perfect,
+            mods: score.mods.legacy().bits(),
+            life_bar: Vec::new(),
+            timestamp: score.ended_at,
+            frames,
+            rng_seed: None,
+            score_id: score.score_id.unwrap_or(0),
+            target_practice_accuracy: None,
+            lazer: Some(lazer),
+        }
+    }
+
+    /// The legacy mods of the replay, ignoring mods that [`GameMods`] does not know of
+    #[inline]
+    pub fn game_mods(&self) -> GameMods {
+        GameMods::from_bits_truncate(self.mods)
+    }
+
+    /// Create the content of a `.osr` file
+    pub fn to_bytes(&self) -> Result<Vec<u8>, ReplayError> {
+        let mut bytes = Vec::with_capacity(256 + self.frames.len() * 8);
+
+        let mods = match self.target_practice_accuracy {
+            Some(_) => self.mods | TARGET_PRACTICE,
+            None => self.mods & !TARGET_PRACTICE,
+        };
+
+        bytes.push(self.mode as u8);
+        bytes.extend_from_slice(&self.version.to_le_bytes());
+        write_string(&mut bytes, self.map_hash.as_deref());
+        write_string(&mut bytes, self.player_name.as_deref());
+        write_string(&mut bytes, self.replay_hash.as_deref());
+
+        for count in [
+            self.count_300,
+            self.count_100,
+            self.count_50,
+            self.count_geki,
+            self.count_katu,
+            self.count_miss,
+        ] {
+            bytes.extend_from_slice(&count.to_le_bytes());
+        }
+
+        bytes.extend_from_slice(&self.score.to_le_bytes());
+        bytes.extend_from_slice(&self.max_combo.to_le_bytes());
+        bytes.push(self.perfect as u8);
+        bytes.extend_from_slice(&mods.to_le_bytes());
+
+        let life_bar = join(&self.life_bar, |frame, buf| {
+            write!(buf, "{}|{}", frame.time, frame.hp)
+        });
+
+        write_string(&mut bytes, Some(&life_bar));
+        bytes.extend_from_slice(&datetime_to_ticks(self.timestamp).to_le_bytes());
+
+        let seed = self.rng_seed.map(|seed| ReplayFrame {
+            delta: RNG_SEED_DELTA,
+            x: 0.0,
+            y: 0.0,
+            keys: seed,
+        });
+
+        let frames = join(self.frames.iter().chain(seed.as_ref()), |frame, buf| {
+            write!(
+                buf,
+                "{}|{}|{}|{}",
+                frame.delta, frame.x, frame.y, frame.keys
+            )
+        });
+
+        write_compressed(&mut bytes, frames.as_bytes())?;
+        bytes.extend_from_slice(&self.score_id.to_le_bytes());
+
+        if let Some(accuracy) = self.target_practice_accuracy {
+            bytes.extend_from_slice(&accuracy.to_le_bytes());
+        }
+
+        if let Some(ref lazer) = self.lazer {
+            let json = serde_json::to_vec(lazer).map_err(ReplayError::LazerData)?;
+            write_compressed(&mut bytes, &json)?;
+        }
+
+        Ok(bytes)
+    }
+}
+
+fn write_compressed(bytes: &mut Vec<u8>, data: &[u8]) -> Result<(), ReplayError> {
+    let options = Options {
+        unpacked_size: UnpackedSize::WriteToHeader(Some(data.len() as u64)),
+    };
+
+    let mut compressed = Vec::new();
+    lzma_rs::lzma_compress_with_options(&mut Cursor::new(data), &mut compressed, &options)
+        .map_err(ReplayError::Compress)?;
+
+    bytes.extend_from_slice(&(compressed.len() as u32).to_le_bytes());
+    bytes.extend_from_slice(&compressed);
+
+    Ok(())
+}
+
+fn decompress(compressed: &[u8]) -> Result<Vec<u8>, ReplayError> {
+    let mut bytes = Vec::new();
+
+    lzma_rs::lzma_decompress(&mut Cursor::new(compressed), &mut bytes)
+        .map_err(ReplayError::Decompress)?;
+
+    Ok(bytes)
+}
+
+/// Join items to a comma-separated list, including a trailing comma
+fn join<'i, I, T, F>(items: I, f: F) -> String
+where
+    I: IntoIterator<Item = &'i T>,
+    T: 'i,
+    F: Fn(&T, &mut String) -> FmtResult,
+{
+    let mut buf = String::new();
+
+    for item in items {
+        // Writing to a String does not fail
+        let _ = f(item, &mut buf);
+        buf.push(',');
+    }
+
+    buf
+}
+
+fn parse_value<T: FromStr>(value: Option<&str>, item: &str) -> Result<T, ReplayError> {
+    value
+        .and_then(|value| value.trim().parse().ok())
+        .ok_or_else(|| ReplayError::Frame(item.to_owned()))
+}
+
+fn parse_life_bar(life_bar: &str) -> Result<Vec<LifeBarFrame>, ReplayError> {
+    split_items(life_bar)
+        .map(|item| {
+            let mut split = item.split('|');
+
+            Ok(LifeBarFrame {
+                time: parse_value(split.next(), item)?,
+                hp: parse_value(split.next(), item)?,
+            })
+        })
+        .collect()
+}
+
+fn parse_frames(bytes: &[u8]) -> Result<Vec<ReplayFrame>, ReplayError> {
+    let frames = std::str::from_utf8(bytes).map_err(|_| ReplayError::Utf8)?;
+
+    split_items(frames)
+        .map(|item| {
+            let mut split = item.split('|');
+
+            let delta = parse_value(split.next(), item)?;
+            let x = parse_value(split.next(), item)?;
+            let y = parse_value(split.next(), item)?;
+
+            // Some clients store the keys as floating point number
+            let keys = parse_value::<f64>(split.next(), item)? as u32;
+
+            Ok(ReplayFrame { delta, x, y, keys })
+        })
+        .collect()
+}
+
+fn split_items(s: &str) -> impl Iterator<Item = &str> {
+    s.split(',').filter(|item| !item.trim().is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn replay() -> Replay {
+        Replay {
+            mode: GameMode::Mania,
+            version: 20_240_123,
+            map_hash: Some("d41d8cd98f00b204e9800998ecf8427e".to_owned()),
+            player_name: Some("Badewanne3".to_owned()),
+            replay_hash: None,
+            count_300: 1000,
+            count_100: 20,
+            count_50: 3,
+            count_geki: 1500,
+            count_katu: 40,
+            count_miss: 1,
+            score: 987_654,
+            max_combo: 2000,
+            perfect: false,
+            mods: (GameMods::Hidden | GameMods::Key4).bits(),
+            life_bar: vec![
+                LifeBarFrame { time: 0, hp: 1.0 },
+                LifeBarFrame {
+                    time: 1500,
+                    hp: 0.75,
+                },
+            ],
+            timestamp: OffsetDateTime::from_unix_timestamp(1_600_000_000).unwrap(),
+            frames: vec![
+                ReplayFrame {
+                    delta: 0,
+                    x: 256.0,
+                    y: -500.0,
+                    keys: 0,
+                },
+                ReplayFrame {
+                    delta: 16,
+                    x: 5.0,
+                    y: 0.0,
+                    keys: 0,
+                },
+            ],
+            rng_seed: Some(1337),
+            score_id: 123_456_789,
+            target_practice_accuracy: None,
+            lazer: None,
+        }
+    }
+
+    #[test]
+    fn roundtrip() {
+        let replay = replay();
+        let bytes = replay.to_bytes().unwrap();
+
+        assert_eq!(Replay::from_bytes(&bytes).unwrap(), replay);
+    }
+
+    #[test]
+    fn roundtrip_lazer() {
+        let mut replay = replay();
+        replay.version = Replay::LATEST_VERSION;
+        replay.lazer = Some(LazerReplayData {
+            score_id: Some(42),
+            mods: GameModsIntermediate::from(GameMods::Hidden),
+            statistics: LazerScoreStatistics {
+                perfect: 1500,
+                great: 1000,
+                ..Default::default()
+            },
+            user_id: Some(2),
+            ..Default::default()
+        });
+
+        let bytes = replay.to_bytes().unwrap();
+
+        assert_eq!(Replay::from_bytes(&bytes).unwrap(), replay);
+    }
+
+    #[test]
+    fn parse_stable() {
+        let replay = Replay::from_bytes(include_bytes!("../../tests/fixtures/stable.osr")).unwrap();
+
+        assert_eq!(replay.mode, GameMode::Osu);
+        assert_eq!(replay.version, 20_190_207);
+        assert_eq!(
+            replay.map_hash.as_deref(),
+            Some("a708a5b90349e98b399f2a1c9fce5422")
+        );
+        assert_eq!(replay.player_name.as_deref(), Some("Badewanne3"));
+        assert_eq!(
+            (replay.count_300, replay.count_100, replay.count_miss),
+            (1062, 14, 1)
+        );
+        assert_eq!(replay.max_combo, 1475);
+        assert_eq!(replay.game_mods(), GameMods::Hidden | GameMods::DoubleTime);
+        assert_eq!(replay.life_bar.len(), 5);
+        assert_eq!(
+            replay.life_bar[2],
+            LifeBarFrame {
+                time: 11126,
+                hp: 0.96
+            }
+        );
+        assert_eq!(replay.timestamp.unix_timestamp(), 1_550_171_550);
+        assert_eq!(replay.frames.len(), 5);
+        assert_eq!(replay.frames[2].x, 255.0625);
+        assert_eq!(replay.rng_seed, Some(7_364_201));
+        assert_eq!(replay.score_id, 2_777_408_812);
+        assert!(replay.lazer.is_none());
+    }
+
+    #[test]
+    fn parse_lazer() {
+        let replay = Replay::from_bytes(include_bytes!("../../tests/fixtures/lazer.osr")).unwrap();
+
+        assert_eq!(replay.version, 30_000_016);
+        assert!(replay.replay_hash.is_none());
+        assert!(replay.life_bar.is_empty());
+        assert_eq!(replay.frames.len(), 4);
+        assert_eq!(replay.frames[3].keys, 5);
+        assert!(replay.rng_seed.is_none());
+        assert_eq!(replay.score_id, 0);
+
+        let lazer = replay.lazer.unwrap();
+
+        assert_eq!(lazer.score_id, Some(3_260_551_987));
+        assert_eq!(lazer.mods.to_string(), "DT(1.3x)CL");
+        assert!((lazer.mods.clock_rate() - 1.3).abs() < f64::EPSILON);
+        assert_eq!(lazer.statistics.great, 721);
+        assert_eq!(lazer.statistics.slider_tail_hit, 301);
+        assert_eq!(lazer.maximum_statistics.great, 732);
+        assert_eq!(lazer.client_version.as_deref(), Some("2024.1208.0-lazer"));
+        assert_eq!(lazer.user_id, Some(2));
+        assert_eq!(lazer.total_score_without_mods, Some(803_516));
+    }
+
+    #[test]
+    fn truncated() {
+        let bytes = replay().to_bytes().unwrap();
+
+        assert!(matches!(
+            Replay::from_bytes(&bytes[..bytes.len() / 2]),
+            Err(ReplayError::UnexpectedEof)
+        ));
+    }
+}
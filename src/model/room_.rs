@@ -0,0 +1,160 @@
+use super::{
+    beatmap::BeatmapCompact, game_mod::GameModsIntermediate, serde_, user_::UserCompact, GameMode,
+};
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use time::OffsetDateTime;
+
+#[cfg(feature = "rkyv")]
+use rkyv::{Archive, Deserialize as RkyvDeserialize, Serialize as RkyvSerialize};
+
+/// A beatmap within the playlist of a [`Room`]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
+pub struct PlaylistItem {
+    /// Mods that players may freely choose
+    pub allowed_mods: GameModsIntermediate,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub beatmap: Option<BeatmapCompact>,
+    /// Whether the item can no longer be played
+    pub expired: bool,
+    #[serde(rename = "id")]
+    pub item_id: u64,
+    #[serde(rename = "beatmap_id")]
+    pub map_id: u32,
+    #[serde(rename = "ruleset_id")]
+    pub mode: GameMode,
+    /// User id of the player that added the item
+    pub owner_id: u32,
+    /// When the item was played, `None` if it has not been played yet
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "serde_::option_datetime"
+    )]
+    #[cfg_attr(feature = "rkyv", with(super::rkyv_impls::DateTimeMap))]
+    pub played_at: Option<OffsetDateTime>,
+    /// Mods that every score must be set with
+    pub required_mods: GameModsIntermediate,
+    pub room_id: u64,
+}
+
+/// A multiplayer room or playlist of the lazer client
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
+pub struct Room {
+    /// Whether the room is still open
+    pub active: bool,
+    pub category: RoomCategory,
+    /// The playlist item that is currently played, or in case of
+    /// the daily challenge, the beatmap of the day
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub current_playlist_item: Option<PlaylistItem>,
+    /// When the room closes, `None` if it has no fixed end
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "serde_::option_datetime"
+    )]
+    #[cfg_attr(feature = "rkyv", with(super::rkyv_impls::DateTimeMap))]
+    pub ends_at: Option<OffsetDateTime>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub host: Option<UserCompact>,
+    pub name: String,
+    /// Amount of players that have played in the room
+    pub participant_count: u32,
+    #[serde(rename = "id")]
+    pub room_id: u64,
+    #[serde(with = "serde_::datetime")]
+    #[cfg_attr(feature = "rkyv", with(super::rkyv_impls::DateTimeWrapper))]
+    pub starts_at: OffsetDateTime,
+    /// User id of the host
+    pub user_id: u32,
+}
+
+/// The kind of a [`Room`]
+#[derive(Copy, Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
+#[serde(rename_all = "snake_case")]
+pub enum RoomCategory {
+    Normal,
+    Spotlight,
+    FeaturedArtist,
+    DailyChallenge,
+}
+
+impl fmt::Display for RoomCategory {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let category = match self {
+            Self::Normal => "normal",
+            Self::Spotlight => "spotlight",
+            Self::FeaturedArtist => "featured_artist",
+            Self::DailyChallenge => "daily_challenge",
+        };
+
+        f.write_str(category)
+    }
+}
+
+/// The leaderboard of a [`Room`]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
+pub struct RoomLeaderboard {
+    /// The top entries of the leaderboard
+    pub leaderboard: Vec<RoomScore>,
+    /// The entry of the authenticated user, if available
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_score: Option<RoomScore>,
+}
+
+/// The accumulated scores of a user across all playlist items of a [`Room`]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
+pub struct RoomScore {
+    pub accuracy: f32,
+    /// Amount of plays across all playlist items
+    pub attempts: u32,
+    /// Amount of completed playlist items
+    pub completed: u32,
+    /// The position within the leaderboard, only available for the user's own score
+    #[serde(default, rename = "position", skip_serializing_if = "Option::is_none")]
+    pub pos: Option<u32>,
+    pub pp: f32,
+    pub room_id: u64,
+    pub total_score: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<UserCompact>,
+    pub user_id: u32,
+}
+
+/// A season of ranked playlists
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
+pub struct Season {
+    /// Whether the season is over
+    pub ended: bool,
+    /// When the season ends, `None` if it has no fixed end
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "serde_::option_datetime"
+    )]
+    #[cfg_attr(feature = "rkyv", with(super::rkyv_impls::DateTimeMap))]
+    pub end_date: Option<OffsetDateTime>,
+    pub name: String,
+    /// Amount of playlists within the season
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub room_count: Option<u32>,
+    #[serde(rename = "id")]
+    pub season_id: u32,
+    /// When the season starts, `None` if it has not been scheduled yet
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "serde_::option_datetime"
+    )]
+    #[cfg_attr(feature = "rkyv", with(super::rkyv_impls::DateTimeMap))]
+    pub start_date: Option<OffsetDateTime>,
+}
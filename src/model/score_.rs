@@ -2,19 +2,61 @@ use super::{
     beatmap::{Beatmap, BeatmapsetCompact},
     serde_,
     user_::UserCompact,
-    GameMode, GameMods, Grade,
+    GameMode, GameMods, GameModsIntermediate, Grade,
 };
 use crate::{request::GetUser, Osu};
 
 use serde::{Deserialize, Serialize};
+use std::fmt;
 
 #[cfg(feature = "rkyv")]
 use rkyv::{Archive, Deserialize as RkyvDeserialize, Serialize as RkyvSerialize};
 use time::OffsetDateTime;
 
-#[derive(Debug, Deserialize)]
-pub(crate) struct BeatmapScores {
-    pub(crate) scores: Vec<Score>,
+/// A beatmap leaderboard
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
+pub struct BeatmapScores {
+    /// The top scores of the leaderboard
+    pub scores: Vec<Score>,
+    /// The score of the authenticated user, if available
+    #[serde(default, alias = "userScore", skip_serializing_if = "Option::is_none")]
+    pub user_score: Option<BeatmapUserScore>,
+}
+
+/// The kind of a beatmap leaderboard.
+///
+/// All but [`Global`](LeaderboardType::Global) require osu!supporter
+/// of the authenticated user.
+#[derive(Copy, Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LeaderboardType {
+    /// All scores
+    Global,
+    /// Only scores of users from the same country as the authenticated user
+    Country,
+    /// Only scores of friends of the authenticated user
+    Friend,
+}
+
+impl Default for LeaderboardType {
+    #[inline]
+    fn default() -> Self {
+        Self::Global
+    }
+}
+
+impl fmt::Display for LeaderboardType {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kind = match self {
+            Self::Global => "global",
+            Self::Country => "country",
+            Self::Friend => "friend",
+        };
+
+        f.write_str(kind)
+    }
 }
 
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
@@ -56,7 +98,7 @@ pub struct Score {
     pub mapset: Option<BeatmapsetCompact>,
     #[serde(alias = "ruleset_id")]
     pub mode: GameMode,
-    pub mods: GameMods,
+    pub mods: GameModsIntermediate,
     #[serde(alias = "legacy_perfect")]
     pub perfect: bool,
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -66,11 +108,17 @@ pub struct Score {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub rank_global: Option<u32>,
     pub replay: Option<bool>,
+    /// The hit results that would have been achieved with a perfect play,
+    /// only available for scores in the lazer format
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub maximum_statistics: Option<LazerScoreStatistics>,
     #[serde(alias = "total_score")]
     pub score: u32,
     #[serde(rename = "best_id")]
     pub score_id: Option<u64>,
-    pub statistics: ScoreStatistics,
+    /// All hit results of the score
+    #[serde(rename = "statistics")]
+    pub lazer_statistics: LazerScoreStatistics,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub user: Option<UserCompact>,
     pub user_id: u32,
@@ -84,18 +132,25 @@ impl Score {
         osu.user(self.user_id)
     }
 
+    /// The hit results converted into the format of osu!stable,
+    /// see [`LazerScoreStatistics::as_legacy`].
+    #[inline]
+    pub fn statistics(&self) -> ScoreStatistics {
+        self.lazer_statistics.as_legacy(self.mode)
+    }
+
     /// Count all hitobjects of the score i.e. for `GameMode::Osu` the amount 300s, 100s, 50s, and misses.
     ///
     /// Note: Includes tiny droplet (misses) for `GameMode::Catch`
     #[inline]
     pub fn total_hits(&self) -> u32 {
-        self.statistics.total_hits(self.mode)
+        self.lazer_statistics.total_hits(self.mode)
     }
 
     /// Calculate the accuracy i.e. `0 <= accuracy <= 100`
     #[inline]
     pub fn accuracy(&self) -> f32 {
-        self.statistics.accuracy(self.mode)
+        self.statistics().accuracy(self.mode)
     }
 
     /// Calculate the grade of the score.
@@ -106,16 +161,19 @@ impl Score {
     ///
     /// This method assumes the score to be a pass i.e. the amount of passed
     /// objects is equal to the beatmaps total amount of objects. Otherwise,
-    /// it may produce an incorrect grade.
+    /// it may produce an incorrect grade. Use [`Score::grade_on_map`] to consider fails.
     pub fn grade(&self, accuracy: Option<f32>) -> Grade {
-        let passed_objects = self.total_hits();
+        grade(&self.statistics(), self.mode, self.mods.legacy(), accuracy)
+    }
 
-        match self.mode {
-            GameMode::Osu => osu_grade(self, passed_objects),
-            GameMode::Taiko => taiko_grade(self, passed_objects),
-            GameMode::Catch => ctb_grade(self, accuracy),
-            GameMode::Mania => mania_grade(self, passed_objects, accuracy),
-        }
+    /// Calculate the grade of the score while considering the object counts
+    /// of the beatmap so that fails result in [`Grade::F`].
+    #[inline]
+    pub fn grade_on_map(&self, map: &Beatmap) -> Grade {
+        let mods = self.mods.legacy();
+
+        self.statistics()
+            .grade_with_total(self.mode, mods, passable_objects(map, self.mode))
     }
 }
 
@@ -135,7 +193,10 @@ pub(crate) struct Scores {
     pub(crate) scores: Vec<Score>,
 }
 
-#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+/// Hit results in the format of osu!stable.
+///
+/// See [`LazerScoreStatistics`] for all hit results of the lazer client.
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
 #[cfg_attr(
     feature = "rkyv",
     derive(Archive, RkyvDeserialize, RkyvSerialize),
@@ -146,11 +207,11 @@ pub struct ScoreStatistics {
     pub count_geki: u32,
     #[serde(alias = "great", default)]
     pub count_300: u32,
-    #[serde(alias = "good", alias = "small_tick_miss", default)]
+    #[serde(alias = "good", default)]
     pub count_katu: u32,
-    #[serde(alias = "ok", alias = "large_tick_hit", default)]
+    #[serde(alias = "ok", default)]
     pub count_100: u32,
-    #[serde(alias = "meh", alias = "small_tick_hit", default)]
+    #[serde(alias = "meh", default)]
     pub count_50: u32,
     #[serde(alias = "miss", default)]
     pub count_miss: u32,
@@ -175,6 +236,55 @@ impl ScoreStatistics {
         amount
     }
 
+    /// Calculate the grade for the given mode and mods.
+    ///
+    /// If `total_objects` is given and the statistics contain fewer hits,
+    /// the score is considered a fail i.e. [`Grade::F`].
+    /// For [`GameMode::Catch`], the total should be the amount of fruits
+    /// and droplets, i.e. the beatmap's max combo.
+    pub fn grade_with_total(
+        &self,
+        mode: GameMode,
+        mods: GameMods,
+        total_objects: Option<u32>,
+    ) -> Grade {
+        let passed = match mode {
+            GameMode::Catch => self.count_300 + self.count_100 + self.count_miss,
+            _ => self.total_hits(mode),
+        };
+
+        if matches!(total_objects, Some(total) if passed < total) {
+            return Grade::F;
+        }
+
+        grade(self, mode, mods, None)
+    }
+
+    /// Calculate the accuracy the way the lazer client does i.e. `0 <= accuracy <= 100`.
+    ///
+    /// The only difference to [`ScoreStatistics::accuracy`] is that in
+    /// [`GameMode::Mania`] perfect hits (geki) weigh slightly more than
+    /// great hits. The value is not rounded.
+    pub fn accuracy_lazer(&self, mode: GameMode) -> f32 {
+        if mode != GameMode::Mania {
+            return self.accuracy(mode);
+        }
+
+        let numerator = self.count_geki * 305
+            + self.count_300 * 300
+            + self.count_katu * 200
+            + self.count_100 * 100
+            + self.count_50 * 50;
+
+        let denominator = self.total_hits(mode) * 305;
+
+        if denominator == 0 {
+            return 0.0;
+        }
+
+        100.0 * numerator as f32 / denominator as f32
+    }
+
     /// Calculate the accuracy rounded to two decimal points i.e. `0 <= accuracy <= 100`
     pub fn accuracy(&self, mode: GameMode) -> f32 {
         let amount_objects = self.total_hits(mode) as f32;
@@ -203,6 +313,351 @@ impl ScoreStatistics {
     }
 }
 
+/// All hit results of a score as tracked by the lazer client.
+///
+/// Which results occur depends on the mode, e.g. in [`GameMode::Catch`] droplets
+/// are large ticks and tiny droplets are small ticks, whereas in [`GameMode::Osu`]
+/// large ticks are slider ticks and slider repeats.
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(Archive, RkyvDeserialize, RkyvSerialize),
+    archive(as = "Self")
+)]
+#[serde(default)]
+pub struct LazerScoreStatistics {
+    pub perfect: u32,
+    pub great: u32,
+    pub good: u32,
+    pub ok: u32,
+    pub meh: u32,
+    pub miss: u32,
+    pub large_tick_hit: u32,
+    pub large_tick_miss: u32,
+    pub small_tick_hit: u32,
+    pub small_tick_miss: u32,
+    pub slider_tail_hit: u32,
+    pub large_bonus: u32,
+    pub small_bonus: u32,
+    pub ignore_hit: u32,
+    pub ignore_miss: u32,
+    pub combo_break: u32,
+    pub legacy_combo_increase: u32,
+}
+
+impl LazerScoreStatistics {
+    /// Convert statistics of osu!stable into lazer hit results.
+    ///
+    /// Since osu!stable does not track all results, e.g. slider ticks for
+    /// [`GameMode::Osu`] or missed droplets for [`GameMode::Catch`],
+    /// those will be zero.
+    pub fn from_legacy(stats: &ScoreStatistics, mode: GameMode) -> Self {
+        let mut lazer = Self {
+            miss: stats.count_miss,
+            ..Default::default()
+        };
+
+        match mode {
+            GameMode::Osu | GameMode::Taiko => {
+                lazer.great = stats.count_300;
+                lazer.ok = stats.count_100;
+                lazer.meh = (mode == GameMode::Osu) as u32 * stats.count_50;
+            }
+            GameMode::Catch => {
+                lazer.great = stats.count_300;
+                lazer.large_tick_hit = stats.count_100;
+                lazer.small_tick_hit = stats.count_50;
+                lazer.small_tick_miss = stats.count_katu;
+            }
+            GameMode::Mania => {
+                lazer.perfect = stats.count_geki;
+                lazer.great = stats.count_300;
+                lazer.good = stats.count_katu;
+                lazer.ok = stats.count_100;
+                lazer.meh = stats.count_50;
+            }
+        }
+
+        lazer
+    }
+
+    /// Convert the hit results into the format of osu!stable.
+    ///
+    /// Results that osu!stable does not know of, e.g. slider ticks or bonus
+    /// results, are not mixed into any of the legacy counts.
+    pub fn as_legacy(&self, mode: GameMode) -> ScoreStatistics {
+        let mut stats = ScoreStatistics {
+            count_miss: self.miss,
+            ..Default::default()
+        };
+
+        match mode {
+            GameMode::Osu | GameMode::Taiko => {
+                stats.count_300 = self.great;
+                stats.count_100 = self.ok;
+                stats.count_50 = (mode == GameMode::Osu) as u32 * self.meh;
+            }
+            GameMode::Catch => {
+                stats.count_300 = self.great;
+                stats.count_100 = self.large_tick_hit;
+                stats.count_50 = self.small_tick_hit;
+                stats.count_katu = self.small_tick_miss;
+                stats.count_miss += self.large_tick_miss;
+            }
+            GameMode::Mania => {
+                stats.count_geki = self.perfect;
+                stats.count_300 = self.great;
+                stats.count_katu = self.good;
+                stats.count_100 = self.ok;
+                stats.count_50 = self.meh;
+            }
+        }
+
+        stats
+    }
+
+    /// Count all hitobjects of the score i.e. for `GameMode::Osu` the amount of greats, oks, mehs, and misses.
+    ///
+    /// Note: Includes droplets and tiny droplets for `GameMode::Catch`
+    pub fn total_hits(&self, mode: GameMode) -> u32 {
+        match mode {
+            GameMode::Osu => self.great + self.ok + self.meh + self.miss,
+            GameMode::Taiko => self.great + self.ok + self.miss,
+            GameMode::Catch => {
+                self.great
+                    + self.miss
+                    + self.large_tick_hit
+                    + self.large_tick_miss
+                    + self.small_tick_hit
+                    + self.small_tick_miss
+            }
+            GameMode::Mania => {
+                self.perfect + self.great + self.good + self.ok + self.meh + self.miss
+            }
+        }
+    }
+
+    /// Calculate the accuracy the way the lazer client does i.e. `0 <= accuracy <= 100`.
+    ///
+    /// Each result is weighted by its base score, e.g. slider ticks count less than circles.
+    /// For [`GameMode::Catch`], fruits, droplets, and tiny droplets are weighted equally.
+    /// Since misses of slider tails are not tracked, prefer
+    /// [`accuracy_with_max`](LazerScoreStatistics::accuracy_with_max)
+    /// if the maximum statistics are available. The value is not rounded.
+    pub fn accuracy(&self, mode: GameMode) -> f32 {
+        accuracy(self.base_score(mode), self.max_base_score(mode))
+    }
+
+    /// Calculate the accuracy in relation to the statistics of a perfect play
+    /// i.e. `0 <= accuracy <= 100`, see [`Score::maximum_statistics`].
+    #[inline]
+    pub fn accuracy_with_max(&self, mode: GameMode, max: &Self) -> f32 {
+        accuracy(self.base_score(mode), max.base_score(mode))
+    }
+
+    fn base_score(&self, mode: GameMode) -> u32 {
+        let ticks = self.large_tick_hit * 30 + self.small_tick_hit * 10;
+
+        match mode {
+            GameMode::Osu => {
+                self.great * 300
+                    + self.ok * 100
+                    + self.meh * 50
+                    + self.slider_tail_hit * 150
+                    + ticks
+            }
+            GameMode::Taiko => self.great * 300 + self.ok * 150,
+            GameMode::Catch => (self.great + self.large_tick_hit + self.small_tick_hit) * 300,
+            GameMode::Mania => {
+                self.perfect * 305
+                    + self.great * 300
+                    + self.good * 200
+                    + self.ok * 100
+                    + self.meh * 50
+            }
+        }
+    }
+
+    fn max_base_score(&self, mode: GameMode) -> u32 {
+        let ticks = (self.large_tick_hit + self.large_tick_miss) * 30
+            + (self.small_tick_hit + self.small_tick_miss) * 10;
+
+        match mode {
+            GameMode::Osu => {
+                (self.great + self.ok + self.meh + self.miss) * 300
+                    + self.slider_tail_hit * 150
+                    + ticks
+            }
+            GameMode::Taiko => self.total_hits(mode) * 300,
+            GameMode::Catch => self.total_hits(mode) * 300,
+            GameMode::Mania => self.total_hits(mode) * 305,
+        }
+    }
+}
+
+fn accuracy(base_score: u32, max_base_score: u32) -> f32 {
+    if max_base_score == 0 {
+        return 0.0;
+    }
+
+    100.0 * base_score as f32 / max_base_score as f32
+}
+
+/// Builder for hypothetical [`ScoreStatistics`], e.g. to see what a
+/// full combo or a specific accuracy would look like on a beatmap.
+///
+/// Hits are distributed such that the resulting accuracy is as close to
+/// the target accuracy as possible, preferring 100s over 50s.
+#[derive(Clone, Debug)]
+pub struct ScoreStatisticsBuilder {
+    mode: GameMode,
+    total_objects: u32,
+    passed_objects: Option<u32>,
+    accuracy: Option<f32>,
+    misses: u32,
+    lazer: bool,
+}
+
+impl ScoreStatisticsBuilder {
+    /// Create a builder for the given mode and total amount of objects.
+    ///
+    /// For [`GameMode::Catch`], the total should be the amount of fruits
+    /// and droplets, i.e. the beatmap's max combo.
+    #[inline]
+    pub fn new(mode: GameMode, total_objects: u32) -> Self {
+        Self {
+            mode,
+            total_objects,
+            passed_objects: None,
+            accuracy: None,
+            misses: 0,
+            lazer: false,
+        }
+    }
+
+    /// Create a builder based on the object counts of the beatmap in its own mode.
+    ///
+    /// Returns `None` for [`GameMode::Catch`] if the map's `max_combo` is not available.
+    /// Use [`ScoreStatisticsBuilder::new`] to specify the total explicitly in that case.
+    #[inline]
+    pub fn from_map(map: &Beatmap) -> Option<Self> {
+        Self::from_map_with_mode(map, map.mode)
+    }
+
+    /// Create a builder based on the object counts of the beatmap in the given mode.
+    ///
+    /// Returns `None` for [`GameMode::Catch`] if the map's `max_combo` is not available.
+    /// Use [`ScoreStatisticsBuilder::new`] to specify the total explicitly in that case.
+    #[inline]
+    pub fn from_map_with_mode(map: &Beatmap, mode: GameMode) -> Option<Self> {
+        passable_objects(map, mode).map(|total_objects| Self::new(mode, total_objects))
+    }
+
+    /// Specify the target accuracy between 0 and 100. Defaults to 100.
+    #[inline]
+    pub fn accuracy(mut self, accuracy: f32) -> Self {
+        self.accuracy.replace(accuracy.clamp(0.0, 100.0));
+
+        self
+    }
+
+    /// Specify the amount of misses. Defaults to 0.
+    #[inline]
+    pub fn misses(mut self, misses: u32) -> Self {
+        self.misses = misses;
+
+        self
+    }
+
+    /// Specify the amount of objects that were played before failing.
+    /// Defaults to all objects.
+    #[inline]
+    pub fn passed_objects(mut self, passed_objects: u32) -> Self {
+        self.passed_objects
+            .replace(passed_objects.min(self.total_objects));
+
+        self
+    }
+
+    /// Whether the target accuracy should be reached with the accuracy rules of
+    /// the lazer client, see [`ScoreStatistics::accuracy_lazer`]. Defaults to `false`.
+    #[inline]
+    pub fn lazer(mut self, lazer: bool) -> Self {
+        self.lazer = lazer;
+
+        self
+    }
+
+    /// Create the [`ScoreStatistics`]
+    pub fn build(&self) -> ScoreStatistics {
+        let passed = self.passed_objects.unwrap_or(self.total_objects);
+        let misses = self.misses.min(passed);
+        let remaining = passed - misses;
+        let accuracy = self.accuracy.unwrap_or(100.0) / 100.0;
+
+        let mut stats = ScoreStatistics {
+            count_geki: 0,
+            count_300: 0,
+            count_katu: 0,
+            count_100: 0,
+            count_50: 0,
+            count_miss: misses,
+        };
+
+        match self.mode {
+            GameMode::Osu | GameMode::Mania => {
+                let unit = if self.mode == GameMode::Mania && self.lazer {
+                    305
+                } else {
+                    300
+                };
+
+                let target = (accuracy * (unit * passed) as f32).round() as u32;
+                let mut delta = (unit * remaining).saturating_sub(target);
+
+                // Turn 300s into 100s, then 100s into 50s
+                let n100 = (delta / (unit - 100)).min(remaining);
+                delta -= n100 * (unit - 100);
+                let n50 = (delta / 50).min(n100);
+
+                stats.count_100 = n100 - n50;
+                stats.count_50 = n50;
+
+                if self.mode == GameMode::Mania {
+                    stats.count_geki = remaining - n100;
+                } else {
+                    stats.count_300 = remaining - n100;
+                }
+            }
+            GameMode::Taiko => {
+                let target = accuracy * passed as f32;
+                let n100 = (2.0 * (remaining as f32 - target)).round().max(0.0) as u32;
+
+                stats.count_100 = n100.min(remaining);
+                stats.count_300 = remaining - stats.count_100;
+            }
+            GameMode::Catch => {
+                stats.count_300 = remaining;
+
+                // Missed tiny droplets are the only way to lower accuracy without misses
+                if accuracy > 0.0 {
+                    let total = (remaining as f32 / accuracy).round() as u32;
+                    stats.count_katu = total.saturating_sub(passed);
+                }
+            }
+        }
+
+        stats
+    }
+
+    /// Create the [`ScoreStatistics`] and calculate their grade for the given mods.
+    #[inline]
+    pub fn grade(&self, mods: GameMods) -> Grade {
+        self.build()
+            .grade_with_total(self.mode, mods, Some(self.total_objects))
+    }
+}
+
 #[derive(Copy, Clone, Debug, Deserialize, PartialEq, Serialize)]
 #[cfg_attr(
     feature = "rkyv",
@@ -216,26 +671,47 @@ pub struct ScoreWeight {
     pub pp: f32,
 }
 
+/// The amount of objects that a pass on the beatmap in the given mode hits or misses.
+///
+/// Only available for [`GameMode::Catch`] if the map has its `max_combo`.
+fn passable_objects(map: &Beatmap, mode: GameMode) -> Option<u32> {
+    match mode {
+        GameMode::Osu => Some(map.count_objects()),
+        GameMode::Taiko => Some(map.count_circles),
+        GameMode::Catch => map.max_combo,
+        GameMode::Mania => Some(map.count_circles + map.count_sliders),
+    }
+}
+
+fn grade(stats: &ScoreStatistics, mode: GameMode, mods: GameMods, accuracy: Option<f32>) -> Grade {
+    let passed_objects = stats.total_hits(mode);
+
+    match mode {
+        GameMode::Osu => osu_grade(stats, mods, passed_objects),
+        GameMode::Taiko => taiko_grade(stats, mods, passed_objects),
+        GameMode::Catch => ctb_grade(stats, mods, accuracy),
+        GameMode::Mania => mania_grade(stats, mods, passed_objects, accuracy),
+    }
+}
+
 const HDFL: GameMods =
     GameMods::from_bits_truncate(GameMods::Hidden.bits() + GameMods::Flashlight.bits());
 const HDFLFI: GameMods = GameMods::from_bits_truncate(HDFL.bits() + GameMods::FadeIn.bits());
 
-fn osu_grade(score: &Score, passed_objects: u32) -> Grade {
-    if score.statistics.count_300 == passed_objects {
-        return if score.mods.intersects(HDFL) {
+fn osu_grade(stats: &ScoreStatistics, mods: GameMods, passed_objects: u32) -> Grade {
+    if stats.count_300 == passed_objects {
+        return if mods.intersects(HDFL) {
             Grade::XH
         } else {
             Grade::X
         };
     }
 
-    let stats = &score.statistics;
-
     let ratio300 = stats.count_300 as f32 / passed_objects as f32;
     let ratio50 = stats.count_50 as f32 / passed_objects as f32;
 
     if ratio300 > 0.9 && ratio50 < 0.01 && stats.count_miss == 0 {
-        if score.mods.intersects(HDFL) {
+        if mods.intersects(HDFL) {
             Grade::SH
         } else {
             Grade::S
@@ -251,19 +727,24 @@ fn osu_grade(score: &Score, passed_objects: u32) -> Grade {
     }
 }
 
-fn mania_grade(score: &Score, passed_objects: u32, accuracy: Option<f32>) -> Grade {
-    if score.statistics.count_geki == passed_objects {
-        return if score.mods.intersects(HDFLFI) {
+fn mania_grade(
+    stats: &ScoreStatistics,
+    mods: GameMods,
+    passed_objects: u32,
+    accuracy: Option<f32>,
+) -> Grade {
+    if stats.count_geki == passed_objects {
+        return if mods.intersects(HDFLFI) {
             Grade::XH
         } else {
             Grade::X
         };
     }
 
-    let accuracy = accuracy.unwrap_or_else(|| score.accuracy());
+    let accuracy = accuracy.unwrap_or_else(|| stats.accuracy(GameMode::Mania));
 
     if accuracy > 95.0 {
-        if score.mods.intersects(HDFLFI) {
+        if mods.intersects(HDFLFI) {
             Grade::SH
         } else {
             Grade::S
@@ -279,20 +760,19 @@ fn mania_grade(score: &Score, passed_objects: u32, accuracy: Option<f32>) -> Gra
     }
 }
 
-fn taiko_grade(score: &Score, passed_objects: u32) -> Grade {
-    if score.statistics.count_300 == passed_objects {
-        return if score.mods.intersects(HDFL) {
+fn taiko_grade(stats: &ScoreStatistics, mods: GameMods, passed_objects: u32) -> Grade {
+    if stats.count_300 == passed_objects {
+        return if mods.intersects(HDFL) {
             Grade::XH
         } else {
             Grade::X
         };
     }
 
-    let stats = &score.statistics;
     let ratio300 = stats.count_300 as f32 / passed_objects as f32;
 
     if ratio300 > 0.9 && stats.count_miss == 0 {
-        if score.mods.intersects(HDFL) {
+        if mods.intersects(HDFL) {
             Grade::SH
         } else {
             Grade::S
@@ -308,17 +788,17 @@ fn taiko_grade(score: &Score, passed_objects: u32) -> Grade {
     }
 }
 
-fn ctb_grade(score: &Score, accuracy: Option<f32>) -> Grade {
-    let accuracy = accuracy.unwrap_or_else(|| score.accuracy());
+fn ctb_grade(stats: &ScoreStatistics, mods: GameMods, accuracy: Option<f32>) -> Grade {
+    let accuracy = accuracy.unwrap_or_else(|| stats.accuracy(GameMode::Catch));
 
     if (100.0 - accuracy).abs() <= std::f32::EPSILON {
-        if score.mods.intersects(HDFL) {
+        if mods.intersects(HDFL) {
             Grade::XH
         } else {
             Grade::X
         }
     } else if accuracy > 98.0 {
-        if score.mods.intersects(HDFL) {
+        if mods.intersects(HDFL) {
             Grade::SH
         } else {
             Grade::S
@@ -333,3 +813,135 @@ fn ctb_grade(score: &Score, accuracy: Option<f32>) -> Grade {
         Grade::D
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::test_util::map;
+
+    #[test]
+    fn builder_accuracy() {
+        for &mode in &[
+            GameMode::Osu,
+            GameMode::Taiko,
+            GameMode::Catch,
+            GameMode::Mania,
+        ] {
+            for &acc in &[100.0, 98.5, 93.21, 80.0] {
+                let stats = ScoreStatisticsBuilder::new(mode, 1000)
+                    .accuracy(acc)
+                    .misses(2)
+                    .build();
+
+                let diff = (stats.accuracy(mode) - acc).abs();
+                assert!(diff < 0.3 || acc == 100.0, "{:?} {} {:?}", mode, acc, stats);
+                assert_eq!(stats.count_miss, 2);
+            }
+        }
+    }
+
+    #[test]
+    fn builder_fc() {
+        let stats = ScoreStatisticsBuilder::new(GameMode::Osu, 500).build();
+
+        assert_eq!(stats.count_300, 500);
+        assert_eq!(
+            stats.grade_with_total(GameMode::Osu, GameMods::Hidden, Some(500)),
+            Grade::XH
+        );
+    }
+
+    #[test]
+    fn builder_from_map() {
+        let mut map = map(GameMode::Osu);
+
+        let stats = ScoreStatisticsBuilder::from_map(&map).unwrap().build();
+        assert_eq!(stats.count_300, 501);
+
+        assert!(ScoreStatisticsBuilder::from_map_with_mode(&map, GameMode::Catch).is_none());
+
+        map.max_combo = Some(800);
+        let stats = ScoreStatisticsBuilder::from_map_with_mode(&map, GameMode::Catch)
+            .unwrap()
+            .build();
+        assert_eq!(stats.count_300, 800);
+    }
+
+    #[test]
+    fn builder_fail() {
+        let builder = ScoreStatisticsBuilder::new(GameMode::Taiko, 800)
+            .passed_objects(400)
+            .accuracy(99.0);
+
+        assert_eq!(builder.build().total_hits(GameMode::Taiko), 400);
+        assert_eq!(builder.grade(GameMods::NoMod), Grade::F);
+    }
+
+    #[test]
+    fn lazer_statistics_catch() {
+        let lazer = LazerScoreStatistics {
+            great: 500,
+            miss: 2,
+            large_tick_hit: 100,
+            large_tick_miss: 3,
+            small_tick_hit: 400,
+            small_tick_miss: 10,
+            ..Default::default()
+        };
+
+        let legacy = lazer.as_legacy(GameMode::Catch);
+
+        assert_eq!(legacy.count_100, 100);
+        assert_eq!(legacy.count_katu, 10);
+        assert_eq!(legacy.count_miss, 5);
+        assert_eq!(
+            lazer.total_hits(GameMode::Catch),
+            legacy.total_hits(GameMode::Catch)
+        );
+
+        let accuracy = lazer.accuracy(GameMode::Catch);
+        assert!((accuracy - 100.0 * 1000.0 / 1015.0).abs() < 1e-4);
+        assert!((accuracy - legacy.accuracy(GameMode::Catch)).abs() < 0.01);
+
+        let roundtrip = LazerScoreStatistics::from_legacy(&legacy, GameMode::Catch);
+        assert_eq!(roundtrip.as_legacy(GameMode::Catch), legacy);
+    }
+
+    #[test]
+    fn lazer_statistics_osu() {
+        let lazer = LazerScoreStatistics {
+            great: 300,
+            ok: 10,
+            miss: 1,
+            large_tick_hit: 50,
+            large_tick_miss: 2,
+            slider_tail_hit: 40,
+            ..Default::default()
+        };
+
+        let legacy = lazer.as_legacy(GameMode::Osu);
+
+        assert_eq!(legacy.count_100, 10);
+        assert_eq!(lazer.total_hits(GameMode::Osu), 311);
+
+        let max = LazerScoreStatistics {
+            great: 311,
+            large_tick_hit: 52,
+            slider_tail_hit: 42,
+            ..Default::default()
+        };
+
+        assert!(lazer.accuracy_with_max(GameMode::Osu, &max) < lazer.accuracy(GameMode::Osu));
+        assert!((max.accuracy_with_max(GameMode::Osu, &max) - 100.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn lazer_mania_accuracy() {
+        let stats = ScoreStatisticsBuilder::new(GameMode::Mania, 1000)
+            .accuracy(97.0)
+            .lazer(true)
+            .build();
+
+        assert!((stats.accuracy_lazer(GameMode::Mania) - 97.0).abs() < 0.1);
+    }
+}
@@ -0,0 +1,64 @@
+use super::{user_::UserCompact, wiki_::WikiPage};
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+#[cfg(feature = "rkyv")]
+use rkyv::{Archive, Deserialize as RkyvDeserialize, Serialize as RkyvSerialize};
+
+/// Result of a site search
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
+pub struct SearchResult {
+    /// Matching users, unless the search mode was [`SearchMode::WikiPage`]
+    #[serde(default, rename = "user", skip_serializing_if = "Option::is_none")]
+    pub users: Option<SearchHits<UserCompact>>,
+    /// Matching wiki pages, unless the search mode was [`SearchMode::User`]
+    #[serde(default, rename = "wiki_page", skip_serializing_if = "Option::is_none")]
+    pub wiki_pages: Option<SearchHits<WikiPage>>,
+}
+
+/// Hits of a single page of a site search
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
+pub struct SearchHits<T> {
+    /// The hits on the requested page
+    #[serde(rename = "data")]
+    pub hits: Vec<T>,
+    /// Total amount of hits across all pages
+    pub total: u32,
+}
+
+/// What to search for in a site search
+#[derive(Copy, Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub enum SearchMode {
+    /// Search for both users and wiki pages
+    #[serde(rename = "all")]
+    All,
+    /// Search only for users
+    #[serde(rename = "user")]
+    User,
+    /// Search only for wiki pages
+    #[serde(rename = "wiki_page")]
+    WikiPage,
+}
+
+impl Default for SearchMode {
+    #[inline]
+    fn default() -> Self {
+        Self::All
+    }
+}
+
+impl fmt::Display for SearchMode {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mode = match self {
+            Self::All => "all",
+            Self::User => "user",
+            Self::WikiPage => "wiki_page",
+        };
+
+        f.write_str(mode)
+    }
+}
@@ -0,0 +1,731 @@
+use super::beatmap::RankStatus;
+
+use std::{
+    convert::TryFrom,
+    fmt::{Display, Formatter, Result as FmtResult, Write},
+    ops::{Bound, RangeBounds},
+};
+use time::{Date, Month};
+
+/// A typed query for [`GetBeatmapsetSearch::query`](crate::request::GetBeatmapsetSearch::query).
+///
+/// Renders to the inline filter syntax of the osu! website through its `Display`
+/// implementation and can be parsed back from a query string with [`SearchQuery::parse`].
+///
+/// ## Example
+///
+/// ```
+/// use rosu_v2::model::beatmap::{RankStatus, SearchQuery};
+///
+/// let query = SearchQuery::new()
+///     .text("camellia")
+///     .phrase("ghost rule")
+///     .stars(6.5..)
+///     .ar(..=9.5)
+///     .status(RankStatus::Loved);
+///
+/// assert_eq!(
+///     query.to_string(),
+///     r#"camellia "ghost rule" ar<=9.5 stars>=6.5 status=loved"#
+/// );
+///
+/// assert_eq!(SearchQuery::parse(&query.to_string()), query);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SearchQuery {
+    /// Free text that is not bound to a specific attribute
+    pub terms: Vec<SearchTerm>,
+    pub ar: SearchRange<f32>,
+    pub bpm: SearchRange<f32>,
+    pub cs: SearchRange<f32>,
+    /// HP drain rate
+    pub dr: SearchRange<f32>,
+    pub od: SearchRange<f32>,
+    pub stars: SearchRange<f32>,
+    /// Amount of keys for mania maps
+    pub keys: SearchRange<u32>,
+    /// Drain length in seconds
+    pub length: SearchRange<u32>,
+    pub created: SearchRange<Date>,
+    pub ranked: SearchRange<Date>,
+    pub updated: SearchRange<Date>,
+    pub artist: Option<String>,
+    pub creator: Option<String>,
+    /// Name of a difficulty of the mapset
+    pub difficulty: Option<String>,
+    pub source: Option<String>,
+    pub tag: Option<String>,
+    pub title: Option<String>,
+    pub status: Option<RankStatus>,
+}
+
+/// Free text of a [`SearchQuery`]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SearchTerm {
+    /// A single word
+    Word(String),
+    /// Multiple words that have to occur in this exact order
+    Phrase(String),
+}
+
+/// The lower and upper bound of an attribute in a [`SearchQuery`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct SearchRange<T> {
+    pub lower: Bound<T>,
+    pub upper: Bound<T>,
+}
+
+impl<T> SearchRange<T> {
+    /// Whether neither bound is set
+    #[inline]
+    pub fn is_unbounded(&self) -> bool {
+        matches!(
+            (&self.lower, &self.upper),
+            (Bound::Unbounded, Bound::Unbounded)
+        )
+    }
+}
+
+impl<T> Default for SearchRange<T> {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            lower: Bound::Unbounded,
+            upper: Bound::Unbounded,
+        }
+    }
+}
+
+impl<T: Copy> SearchRange<T> {
+    #[inline]
+    fn from_bounds(range: impl RangeBounds<T>) -> Self {
+        Self {
+            lower: copy_bound(range.start_bound()),
+            upper: copy_bound(range.end_bound()),
+        }
+    }
+}
+
+fn copy_bound<T: Copy>(bound: Bound<&T>) -> Bound<T> {
+    match bound {
+        Bound::Included(value) => Bound::Included(*value),
+        Bound::Excluded(value) => Bound::Excluded(*value),
+        Bound::Unbounded => Bound::Unbounded,
+    }
+}
+
+macro_rules! range_methods {
+    ($($field:ident: $ty:ty => $doc:literal,)*) => {
+        $(
+            #[doc = $doc]
+            #[inline]
+            pub fn $field(mut self, range: impl RangeBounds<$ty>) -> Self {
+                self.$field = SearchRange::from_bounds(range);
+
+                self
+            }
+        )*
+    };
+}
+
+macro_rules! text_methods {
+    ($($field:ident => $doc:literal,)*) => {
+        $(
+            #[doc = $doc]
+            #[inline]
+            pub fn $field(mut self, $field: impl Into<String>) -> Self {
+                self.$field.replace($field.into());
+
+                self
+            }
+        )*
+    };
+}
+
+impl SearchQuery {
+    /// Create an empty query
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a single word
+    #[inline]
+    pub fn text(mut self, word: impl Into<String>) -> Self {
+        self.terms.push(SearchTerm::Word(word.into()));
+
+        self
+    }
+
+    /// Add multiple words that have to occur in this exact order
+    #[inline]
+    pub fn phrase(mut self, phrase: impl Into<String>) -> Self {
+        self.terms.push(SearchTerm::Phrase(phrase.into()));
+
+        self
+    }
+
+    range_methods! {
+        ar: f32 => "Specify the range of the approach rate",
+        bpm: f32 => "Specify the range of the BPM",
+        cs: f32 => "Specify the range of the circle size",
+        dr: f32 => "Specify the range of the HP drain rate",
+        od: f32 => "Specify the range of the overall difficulty",
+        stars: f32 => "Specify the range of the star rating",
+        keys: u32 => "Specify the range of the amount of keys for mania maps",
+        length: u32 => "Specify the range of the drain length in seconds",
+        created: Date => "Specify the range of the date of submission",
+        ranked: Date => "Specify the range of the date of being ranked",
+        updated: Date => "Specify the range of the date of the last update",
+    }
+
+    text_methods! {
+        artist => "Specify the artist",
+        creator => "Specify the creator of the mapset",
+        difficulty => "Specify the name of a difficulty",
+        source => "Specify the source",
+        tag => "Specify a tag",
+        title => "Specify the title",
+    }
+
+    /// Specify the status of the mapset
+    #[inline]
+    pub fn status(mut self, status: RankStatus) -> Self {
+        self.status.replace(status);
+
+        self
+    }
+
+    /// Parse a query string into its typed form.
+    ///
+    /// Filters with an unknown key or an invalid value are kept as free text.
+    pub fn parse(query: &str) -> Self {
+        let mut search = Self::default();
+
+        for token in tokenize(query) {
+            if !search.parse_filter(token) {
+                search.terms.push(parse_term(token));
+            }
+        }
+
+        search
+    }
+
+    /// Returns whether the token was a valid filter
+    fn parse_filter(&mut self, token: &str) -> bool {
+        let key_len = token
+            .find(|c: char| !c.is_ascii_alphabetic() && c != '_')
+            .unwrap_or(token.len());
+
+        let (key, rest) = token.split_at(key_len);
+
+        let (op, value) = match Operator::split(rest) {
+            Some(split) => split,
+            None => return false,
+        };
+
+        let unquoted = value
+            .strip_prefix('"')
+            .and_then(|value| value.strip_suffix('"'))
+            .map(unescape);
+
+        let value = unquoted.as_deref().unwrap_or(value);
+
+        if value.is_empty() {
+            return false;
+        }
+
+        match key.to_ascii_lowercase().as_str() {
+            "ar" => op.apply(&mut self.ar, parse_value(value)),
+            "bpm" => op.apply(&mut self.bpm, parse_value(value)),
+            "cs" => op.apply(&mut self.cs, parse_value(value)),
+            "dr" | "hp" => op.apply(&mut self.dr, parse_value(value)),
+            "od" => op.apply(&mut self.od, parse_value(value)),
+            "stars" | "star" => op.apply(&mut self.stars, parse_value(value)),
+            "keys" => op.apply(&mut self.keys, parse_value(value)),
+            "length" => op.apply(&mut self.length, parse_length(value)),
+            "created" => op.apply_date(&mut self.created, parse_date(value)),
+            "ranked" => op.apply_date(&mut self.ranked, parse_date(value)),
+            "updated" => op.apply_date(&mut self.updated, parse_date(value)),
+            "artist" if op.is_eq() => set_text(&mut self.artist, value),
+            "creator" if op.is_eq() => set_text(&mut self.creator, value),
+            "difficulty" | "diff" if op.is_eq() => set_text(&mut self.difficulty, value),
+            "source" if op.is_eq() => set_text(&mut self.source, value),
+            "tag" if op.is_eq() => set_text(&mut self.tag, value),
+            "title" if op.is_eq() => set_text(&mut self.title, value),
+            "status" if op.is_eq() => match parse_status(value) {
+                Some(status) => {
+                    self.status.replace(status);
+
+                    true
+                }
+                None => false,
+            },
+            _ => false,
+        }
+    }
+}
+
+fn set_text(text: &mut Option<String>, value: &str) -> bool {
+    text.replace(value.to_owned());
+
+    true
+}
+
+impl From<&str> for SearchQuery {
+    #[inline]
+    fn from(query: &str) -> Self {
+        Self::parse(query)
+    }
+}
+
+impl From<SearchQuery> for String {
+    #[inline]
+    fn from(query: SearchQuery) -> Self {
+        query.to_string()
+    }
+}
+
+impl Display for SearchQuery {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let mut writer = QueryWriter { f, first: true };
+
+        for term in self.terms.iter() {
+            match term {
+                SearchTerm::Word(word) if !needs_quotes(word) => writer.write(word)?,
+                SearchTerm::Word(text) | SearchTerm::Phrase(text) => {
+                    writer.write(format_args!("\"{}\"", Escaped(text)))?
+                }
+            }
+        }
+
+        writer.range("ar", &self.ar)?;
+        writer.range("bpm", &self.bpm)?;
+        writer.range("cs", &self.cs)?;
+        writer.range("dr", &self.dr)?;
+        writer.range("od", &self.od)?;
+        writer.range("stars", &self.stars)?;
+        writer.range("keys", &self.keys)?;
+        writer.range("length", &self.length)?;
+        writer.date_range("created", &self.created)?;
+        writer.date_range("ranked", &self.ranked)?;
+        writer.date_range("updated", &self.updated)?;
+        writer.text("artist", self.artist.as_deref())?;
+        writer.text("creator", self.creator.as_deref())?;
+        writer.text("difficulty", self.difficulty.as_deref())?;
+        writer.text("source", self.source.as_deref())?;
+        writer.text("tag", self.tag.as_deref())?;
+        writer.text("title", self.title.as_deref())?;
+
+        if let Some(status) = self.status {
+            writer.write(format_args!("status={}", status_str(status)))?;
+        }
+
+        Ok(())
+    }
+}
+
+struct QueryWriter<'a, 'f> {
+    f: &'a mut Formatter<'f>,
+    first: bool,
+}
+
+impl QueryWriter<'_, '_> {
+    fn write(&mut self, item: impl Display) -> FmtResult {
+        if !self.first {
+            self.f.write_str(" ")?;
+        }
+
+        self.first = false;
+
+        item.fmt(self.f)
+    }
+
+    fn range<T: Display + PartialEq>(&mut self, key: &str, range: &SearchRange<T>) -> FmtResult {
+        self.bounds(key, &range.lower, &range.upper, |value| value)
+    }
+
+    fn date_range(&mut self, key: &str, range: &SearchRange<Date>) -> FmtResult {
+        self.bounds(key, &range.lower, &range.upper, FormattedDate)
+    }
+
+    fn bounds<'v, T, D, F>(
+        &mut self,
+        key: &str,
+        lower: &'v Bound<T>,
+        upper: &'v Bound<T>,
+        f: F,
+    ) -> FmtResult
+    where
+        T: PartialEq,
+        D: Display,
+        F: Fn(&'v T) -> D,
+    {
+        match (lower, upper) {
+            (Bound::Included(min), Bound::Included(max)) if min == max => {
+                return self.write(format_args!("{}={}", key, f(min)));
+            }
+            (Bound::Included(min), _) => self.write(format_args!("{}>={}", key, f(min)))?,
+            (Bound::Excluded(min), _) => self.write(format_args!("{}>{}", key, f(min)))?,
+            (Bound::Unbounded, _) => {}
+        }
+
+        match upper {
+            Bound::Included(max) => self.write(format_args!("{}<={}", key, f(max))),
+            Bound::Excluded(max) => self.write(format_args!("{}<{}", key, f(max))),
+            Bound::Unbounded => Ok(()),
+        }
+    }
+
+    fn text(&mut self, key: &str, text: Option<&str>) -> FmtResult {
+        match text {
+            Some(text) if needs_quotes(text) => {
+                self.write(format_args!("{}=\"{}\"", key, Escaped(text)))
+            }
+            Some(text) => self.write(format_args!("{}={}", key, text)),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Text within double quotes whose quotes and backslashes are escaped with a backslash
+struct Escaped<'s>(&'s str);
+
+impl Display for Escaped<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        for c in self.0.chars() {
+            if matches!(c, '"' | '\\') {
+                f.write_char('\\')?;
+            }
+
+            f.write_char(c)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Reverse [`Escaped`] for text that was within double quotes
+fn unescape(text: &str) -> String {
+    let mut unescaped = String::with_capacity(text.len());
+    let mut chars = text.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => unescaped.extend(chars.next()),
+            _ => unescaped.push(c),
+        }
+    }
+
+    unescaped
+}
+
+struct FormattedDate<'d>(&'d Date);
+
+impl Display for FormattedDate<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let date = self.0;
+
+        write!(
+            f,
+            "{:04}-{:02}-{:02}",
+            date.year(),
+            date.month() as u8,
+            date.day()
+        )
+    }
+}
+
+/// Whether the text would not be recognized as a single word
+fn needs_quotes(text: &str) -> bool {
+    text.is_empty()
+        || text
+            .chars()
+            .any(|c| c.is_whitespace() || matches!(c, '"' | '=' | ':' | '<' | '>'))
+}
+
+fn status_str(status: RankStatus) -> &'static str {
+    match status {
+        RankStatus::Graveyard => "graveyard",
+        RankStatus::WIP => "wip",
+        RankStatus::Pending => "pending",
+        RankStatus::Ranked => "ranked",
+        RankStatus::Approved => "approved",
+        RankStatus::Qualified => "qualified",
+        RankStatus::Loved => "loved",
+    }
+}
+
+fn parse_status(status: &str) -> Option<RankStatus> {
+    let status = match status.to_ascii_lowercase().as_str() {
+        "graveyard" | "g" => RankStatus::Graveyard,
+        "wip" | "w" => RankStatus::WIP,
+        "pending" | "p" => RankStatus::Pending,
+        "ranked" | "r" => RankStatus::Ranked,
+        "approved" | "a" => RankStatus::Approved,
+        "qualified" | "q" => RankStatus::Qualified,
+        "loved" | "l" => RankStatus::Loved,
+        _ => return None,
+    };
+
+    Some(status)
+}
+
+#[derive(Copy, Clone)]
+enum Operator {
+    Eq,
+    Less,
+    LessEq,
+    Greater,
+    GreaterEq,
+}
+
+impl Operator {
+    fn split(s: &str) -> Option<(Self, &str)> {
+        [
+            ("<=", Self::LessEq),
+            (">=", Self::GreaterEq),
+            ("<", Self::Less),
+            (">", Self::Greater),
+            ("=", Self::Eq),
+            (":", Self::Eq),
+        ]
+        .iter()
+        .find_map(|&(prefix, op)| s.strip_prefix(prefix).map(|value| (op, value)))
+    }
+
+    fn is_eq(self) -> bool {
+        matches!(self, Self::Eq)
+    }
+
+    /// Returns whether the value was valid
+    fn apply<T: Copy>(self, range: &mut SearchRange<T>, value: Option<T>) -> bool {
+        let value = match value {
+            Some(value) => value,
+            None => return false,
+        };
+
+        match self {
+            Self::Eq => {
+                range.lower = Bound::Included(value);
+                range.upper = Bound::Included(value);
+            }
+            Self::Less => range.upper = Bound::Excluded(value),
+            Self::LessEq => range.upper = Bound::Included(value),
+            Self::Greater => range.lower = Bound::Excluded(value),
+            Self::GreaterEq => range.lower = Bound::Included(value),
+        }
+
+        true
+    }
+
+    /// Partial dates such as `2020-05` span a period from `start` until excluding `end`.
+    ///
+    /// Returns whether the date was valid.
+    fn apply_date(self, range: &mut SearchRange<Date>, dates: Option<(Date, Date)>) -> bool {
+        let (start, end) = match dates {
+            Some(dates) => dates,
+            None => return false,
+        };
+
+        match self {
+            Self::Eq => {
+                range.lower = Bound::Included(start);
+                range.upper = Bound::Excluded(end);
+            }
+            Self::Less => range.upper = Bound::Excluded(start),
+            Self::LessEq => range.upper = Bound::Excluded(end),
+            Self::Greater => range.lower = Bound::Included(end),
+            Self::GreaterEq => range.lower = Bound::Included(start),
+        }
+
+        true
+    }
+}
+
+/// Split the query on whitespace while keeping quoted parts together
+fn tokenize(query: &str) -> impl Iterator<Item = &str> {
+    let mut rest = query;
+
+    std::iter::from_fn(move || {
+        rest = rest.trim_start();
+
+        if rest.is_empty() {
+            return None;
+        }
+
+        let mut in_quotes = false;
+        let mut escaped = false;
+
+        let end = rest
+            .char_indices()
+            .find(|&(_, c)| {
+                if escaped {
+                    escaped = false;
+                } else if c == '\\' && in_quotes {
+                    escaped = true;
+                } else if c == '"' {
+                    in_quotes = !in_quotes;
+                }
+
+                c.is_whitespace() && !in_quotes
+            })
+            .map_or(rest.len(), |(i, _)| i);
+
+        let (token, remaining) = rest.split_at(end);
+        rest = remaining;
+
+        Some(token)
+    })
+}
+
+fn parse_term(token: &str) -> SearchTerm {
+    if token.len() >= 2 && token.starts_with('"') && token.ends_with('"') {
+        SearchTerm::Phrase(unescape(&token[1..token.len() - 1]))
+    } else {
+        SearchTerm::Word(token.to_owned())
+    }
+}
+
+fn parse_value<T: std::str::FromStr>(value: &str) -> Option<T> {
+    value.parse().ok()
+}
+
+/// Parse a length with an optional unit into seconds
+fn parse_length(value: &str) -> Option<u32> {
+    let unit_start = value
+        .find(|c: char| c.is_ascii_alphabetic())
+        .unwrap_or(value.len());
+
+    let (n, unit) = value.split_at(unit_start);
+    let n: f64 = n.parse().ok()?;
+
+    let factor = match unit {
+        "" | "s" => 1.0,
+        "ms" => 0.001,
+        "m" => 60.0,
+        "h" => 3600.0,
+        _ => return None,
+    };
+
+    let secs = n * factor;
+
+    (secs >= 0.0).then(|| secs.round() as u32)
+}
+
+/// Parse a date of the form `YYYY`, `YYYY-MM`, or `YYYY-MM-DD` into the
+/// first day of the period and the first day after the period
+fn parse_date(value: &str) -> Option<(Date, Date)> {
+    let mut split = value.splitn(3, '-');
+
+    let year: i32 = split.next()?.parse().ok()?;
+
+    let month = match split.next() {
+        Some(month) => Some(Month::try_from(month.parse::<u8>().ok()?).ok()?),
+        None => None,
+    };
+
+    let day = match split.next() {
+        Some(day) => Some(day.parse::<u8>().ok()?),
+        None => None,
+    };
+
+    match (month, day) {
+        (None, _) => {
+            let start = Date::from_calendar_date(year, Month::January, 1).ok()?;
+            let end = Date::from_calendar_date(year + 1, Month::January, 1).ok()?;
+
+            Some((start, end))
+        }
+        (Some(month), None) => {
+            let start = Date::from_calendar_date(year, month, 1).ok()?;
+
+            let end = match month {
+                Month::December => Date::from_calendar_date(year + 1, Month::January, 1),
+                _ => Date::from_calendar_date(year, month.next(), 1),
+            }
+            .ok()?;
+
+            Some((start, end))
+        }
+        (Some(month), Some(day)) => {
+            let start = Date::from_calendar_date(year, month, day).ok()?;
+
+            Some((start, start.next_day()?))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_escaped() {
+        let query = SearchQuery::new()
+            .text("a=b")
+            .phrase(r#"say "hi""#)
+            .creator("some mapper")
+            .keys(7..=7)
+            .length(60..180);
+
+        assert_eq!(
+            query.to_string(),
+            r#""a=b" "say \"hi\"" keys=7 length>=60 length<180 creator="some mapper""#
+        );
+    }
+
+    #[test]
+    fn render_special_characters() {
+        let query = SearchQuery::new()
+            .text("rock&roll")
+            .text("c++")
+            .title(r#"the "best" \ song"#);
+
+        let rendered = query.to_string();
+
+        assert_eq!(rendered, r#"rock&roll c++ title="the \"best\" \\ song""#);
+        assert_eq!(SearchQuery::parse(&rendered), query);
+    }
+
+    #[test]
+    fn parse_filters() {
+        let query = SearchQuery::parse(
+            r#"freedom  star>6.5 HP<=5 length>2m creator="some mapper" unknown=1 status=r"#,
+        );
+
+        assert_eq!(
+            query.terms,
+            vec![
+                SearchTerm::Word("freedom".to_owned()),
+                SearchTerm::Word("unknown=1".to_owned())
+            ]
+        );
+        assert_eq!(query.stars.lower, Bound::Excluded(6.5));
+        assert_eq!(query.dr.upper, Bound::Included(5.0));
+        assert_eq!(query.length.lower, Bound::Excluded(120));
+        assert_eq!(query.creator.as_deref(), Some("some mapper"));
+        assert_eq!(query.status, Some(RankStatus::Ranked));
+    }
+
+    #[test]
+    fn parse_dates() {
+        let query = SearchQuery::parse("ranked=2020-12 created<2015");
+
+        let date = |y, m, d| Date::from_calendar_date(y, m, d).unwrap();
+
+        assert_eq!(
+            query.ranked.lower,
+            Bound::Included(date(2020, Month::December, 1))
+        );
+        assert_eq!(
+            query.ranked.upper,
+            Bound::Excluded(date(2021, Month::January, 1))
+        );
+        assert_eq!(
+            query.created.upper,
+            Bound::Excluded(date(2015, Month::January, 1))
+        );
+        assert_eq!(SearchQuery::parse(&query.to_string()), query);
+    }
+}
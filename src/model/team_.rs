@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "rkyv")]
+use rkyv::{Archive, Deserialize as RkyvDeserialize, Serialize as RkyvSerialize};
+
+/// A player team
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
+pub struct Team {
+    /// URL to the team's flag
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub flag_url: Option<String>,
+    /// Unique identifier of the team
+    #[serde(rename = "id")]
+    pub team_id: u32,
+    /// Full name of the team
+    pub name: String,
+    /// Abbreviated name of the team
+    pub short_name: String,
+}
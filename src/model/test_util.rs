@@ -0,0 +1,49 @@
+use super::{
+    beatmap::{Beatmap, RankStatus},
+    GameMode,
+};
+
+use time::OffsetDateTime;
+
+/// A ranked map with 501 objects, 90 seconds of drain time, and an unknown max combo
+pub(crate) fn map(mode: GameMode) -> Beatmap {
+    Beatmap {
+        ar: 9.0,
+        bpm: 180.0,
+        checksum: None,
+        convert: false,
+        count_circles: 300,
+        count_sliders: 200,
+        count_spinners: 1,
+        creator_id: 2,
+        cs: 4.0,
+        deleted_at: None,
+        fail_times: None,
+        hp: 6.0,
+        is_scoreable: true,
+        last_updated: OffsetDateTime::UNIX_EPOCH,
+        map_id: 1,
+        mapset: None,
+        mapset_id: 1,
+        max_combo: None,
+        mode,
+        od: 8.0,
+        passcount: 0,
+        playcount: 0,
+        seconds_drain: 90,
+        seconds_total: 100,
+        stars: 5.0,
+        status: RankStatus::Ranked,
+        url: String::new(),
+        version: String::new(),
+    }
+}
+
+pub(crate) fn assert_approx(actual: f32, expected: f32) {
+    assert!(
+        (actual - expected).abs() < 0.001,
+        "expected {}, got {}",
+        expected,
+        actual
+    );
+}
@@ -1,4 +1,4 @@
-use super::{serde_, GameMode};
+use super::{serde_, team_::Team, GameMode};
 
 use serde::{
     de::{Error, IgnoredAny, MapAccess, SeqAccess, Visitor},
@@ -369,6 +369,12 @@ pub struct User {
     pub badges: Option<Vec<Badge>>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub beatmap_playcounts_count: Option<u32>,
+    #[serde(
+        default,
+        rename = "daily_challenge_user_stats",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub daily_challenge_stats: Option<DailyChallengeUserStats>,
     #[serde(
         default,
         rename = "favourite_beatmapset_count",
@@ -454,6 +460,8 @@ pub struct User {
     pub statistics: Option<UserStatistics>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub support_level: Option<u8>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team: Option<Team>,
     #[serde(
         default,
         rename = "pending_beatmapset_count",
@@ -573,6 +581,8 @@ pub struct UserCompact {
     pub is_nat: Option<bool>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub is_silenced: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kudosu: Option<UserKudosu>,
     #[serde(
         default,
         rename = "loved_beatmapset_count",
@@ -617,6 +627,8 @@ pub struct UserCompact {
     pub statistics: Option<UserStatistics>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub support_level: Option<u8>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub team: Option<Team>,
     #[serde(
         default,
         rename = "pending_beatmapset_count",
@@ -660,6 +672,7 @@ impl From<User> for UserCompact {
             is_moderator: user.is_moderator,
             is_nat: user.is_nat,
             is_silenced: user.is_silenced,
+            kudosu: Some(user.kudosu),
             loved_mapset_count: user.loved_mapset_count,
             medals: user.medals,
             monthly_playcounts: user.monthly_playcounts,
@@ -673,6 +686,7 @@ impl From<User> for UserCompact {
             scores_recent_count: user.scores_recent_count,
             statistics: user.statistics,
             support_level: user.support_level,
+            team: user.team,
             pending_mapset_count: user.pending_mapset_count,
         }
     }
@@ -697,6 +711,45 @@ pub struct UserHighestRank {
     pub updated_at: OffsetDateTime,
 }
 
+/// Daily challenge statistics of a [`User`]
+#[derive(Copy, Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
+pub struct DailyChallengeUserStats {
+    /// Longest streak of consecutive days with a daily challenge play
+    pub daily_streak_best: u32,
+    /// Current streak of consecutive days with a daily challenge play
+    pub daily_streak_current: u32,
+    /// Last time the user played a daily challenge
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "serde_::option_datetime"
+    )]
+    #[cfg_attr(feature = "rkyv", with(super::rkyv_impls::DateTimeMap))]
+    pub last_update: Option<OffsetDateTime>,
+    /// Start of the last week in which the user played a daily challenge
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "serde_::option_datetime"
+    )]
+    #[cfg_attr(feature = "rkyv", with(super::rkyv_impls::DateTimeMap))]
+    pub last_weekly_streak: Option<OffsetDateTime>,
+    /// Total amount of daily challenges played
+    pub playcount: u32,
+    /// How often the user placed within the top 10% of a daily challenge
+    #[serde(rename = "top_10p_placements")]
+    pub top_10_percent_placements: u32,
+    /// How often the user placed within the top 50% of a daily challenge
+    #[serde(rename = "top_50p_placements")]
+    pub top_50_percent_placements: u32,
+    pub user_id: u32,
+    /// Longest streak of consecutive weeks with a daily challenge play
+    pub weekly_streak_best: u32,
+    /// Current streak of consecutive weeks with a daily challenge play
+    pub weekly_streak_current: u32,
+}
+
 /// Kudosu of a [`User`]
 #[derive(Copy, Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 #[cfg_attr(
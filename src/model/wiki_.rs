@@ -3,6 +3,47 @@ use serde::{Deserialize, Serialize};
 #[cfg(feature = "rkyv")]
 use rkyv::{Archive, Deserialize as RkyvDeserialize, Serialize as RkyvSerialize};
 
+/// Locale that the osu! wiki falls back to if an article is not translated
+const FALLBACK_LOCALE: &str = "en";
+
+/// All locales supported by osu-web
+const LOCALES: &[&str] = &[
+    "ar", "be", "bg", "ca", "cs", "da", "de", "el", "en", "es", "fa", "fi", "fil", "fr", "he",
+    "hr", "hu", "id", "it", "ja", "ko", "lt", "lv", "ms", "nl", "no", "pl", "pt", "pt-br", "ro",
+    "ru", "sk", "sl", "sr", "sv", "th", "tl", "tr", "uk", "vi", "zh", "zh-tw",
+];
+
+/// Response of the `wiki/{locale}/{path}` endpoint.
+///
+/// Depending on the path, the endpoint provides either an article or image data.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum WikiResponse {
+    /// A wiki article
+    Page(WikiPage),
+    /// Raw bytes of an image
+    Image(Vec<u8>),
+}
+
+impl WikiResponse {
+    /// Returns the [`WikiPage`] if the response is an article.
+    #[inline]
+    pub fn page(self) -> Option<WikiPage> {
+        match self {
+            Self::Page(page) => Some(page),
+            Self::Image(_) => None,
+        }
+    }
+
+    /// Returns the image data if the response is an image.
+    #[inline]
+    pub fn image(self) -> Option<Vec<u8>> {
+        match self {
+            Self::Page(_) => None,
+            Self::Image(bytes) => Some(bytes),
+        }
+    }
+}
+
 /// Represents a wiki article
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 #[cfg_attr(feature = "rkyv", derive(Archive, RkyvDeserialize, RkyvSerialize))]
@@ -27,3 +68,387 @@ pub struct WikiPage {
     /// The article's title
     pub title: String,
 }
+
+impl WikiPage {
+    /// Returns `locale` if the article is available in it.
+    /// Otherwise, falls back to english or, if that's unavailable too, the page's own locale.
+    pub fn fallback_locale<'l>(&'l self, locale: &'l str) -> &'l str {
+        let available = |locale: &str| {
+            self.available_locales.is_empty() || self.available_locales.iter().any(|l| l == locale)
+        };
+
+        if available(locale) {
+            locale
+        } else if available(FALLBACK_LOCALE) {
+            FALLBACK_LOCALE
+        } else {
+            &self.locale
+        }
+    }
+
+    /// Split the [`markdown`](WikiPage::markdown) into sections, links, and references to other wiki articles.
+    ///
+    /// References without an explicit locale will be resolved to the page's locale.
+    #[inline]
+    pub fn parse(&self) -> WikiContent {
+        self.parse_with_locale(&self.locale)
+    }
+
+    /// Same as [`parse`](WikiPage::parse) but references without an explicit locale will be resolved
+    /// to the given locale, or a fallback if the article is not available in it.
+    pub fn parse_with_locale(&self, locale: &str) -> WikiContent {
+        let locale = self.fallback_locale(locale);
+        let mut content = WikiContent::default();
+        let mut in_code_block = false;
+
+        for line in self.markdown.lines() {
+            let trimmed = line.trim_start();
+
+            if trimmed.starts_with("```") {
+                in_code_block = !in_code_block;
+            } else if !in_code_block {
+                if let Some(section) = parse_heading(trimmed) {
+                    content.sections.push(section);
+
+                    continue;
+                } else if let Some(link) = parse_link_definition(trimmed) {
+                    content.links.push(link);
+
+                    continue;
+                }
+
+                parse_inline_links(line, &mut content.links);
+            }
+
+            let text = match content.sections.last_mut() {
+                Some(section) => &mut section.content,
+                None => &mut content.intro,
+            };
+
+            text.push_str(line);
+            text.push('\n');
+        }
+
+        let trim = |text: &mut String| {
+            let len = text.trim_end().len();
+            text.truncate(len);
+            let start = text.len() - text.trim_start().len();
+            text.drain(..start);
+        };
+
+        trim(&mut content.intro);
+        content
+            .sections
+            .iter_mut()
+            .for_each(|s| trim(&mut s.content));
+
+        content.references = content
+            .links
+            .iter()
+            .filter_map(|link| self.resolve_reference(&link.url, locale))
+            .collect();
+
+        content
+    }
+
+    fn resolve_reference(&self, url: &str, default_locale: &str) -> Option<WikiReference> {
+        let (url, section) = match url.split_once('#') {
+            Some((url, section)) => (url, Some(section.to_owned())),
+            None => (url, None),
+        };
+
+        let path = if let Some(path) = url
+            .strip_prefix("https://osu.ppy.sh/wiki/")
+            .or_else(|| url.strip_prefix("/wiki/"))
+        {
+            path.to_owned()
+        } else if url.is_empty() || url.starts_with('/') || url.contains(':') {
+            return None;
+        } else {
+            // Relative paths are based on the article's directory
+            join_path(&self.path, url)
+        };
+
+        let path = path.trim_matches('/');
+
+        if path.is_empty() {
+            return None;
+        }
+
+        let (locale, path) = match path.split_once('/') {
+            Some((prefix, rest)) if is_locale(prefix) => {
+                (prefix.to_ascii_lowercase(), rest.to_owned())
+            }
+            _ if is_locale(path) => return None,
+            _ => (default_locale.to_owned(), path.to_owned()),
+        };
+
+        Some(WikiReference {
+            locale,
+            path,
+            section,
+        })
+    }
+}
+
+/// Structured content of a [`WikiPage`], created through [`WikiPage::parse`]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct WikiContent {
+    /// Content before the first heading
+    pub intro: String,
+    /// All links of the article, including those to other wiki articles
+    pub links: Vec<WikiLink>,
+    /// Links to wiki articles with their resolved locale
+    pub references: Vec<WikiReference>,
+    /// The article's sections in order of appearance
+    pub sections: Vec<WikiSection>,
+}
+
+/// A section of a wiki article, starting with a heading
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WikiSection {
+    /// Markdown content of the section without the heading itself and without subsections
+    pub content: String,
+    /// Anchor of the section, used as fragment in urls
+    pub id: String,
+    /// Heading level i.e. 1 for `#` up to 6 for `######`
+    pub level: u8,
+    /// The section's heading
+    pub title: String,
+}
+
+/// A markdown link inside a wiki article
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WikiLink {
+    /// The link's text or label for link definitions
+    pub text: String,
+    /// Target of the link as written in the markdown
+    pub url: String,
+}
+
+/// A link to another wiki article
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WikiReference {
+    /// Locale of the referenced article
+    pub locale: String,
+    /// Path of the referenced article
+    pub path: String,
+    /// Anchor of a section inside the referenced article
+    pub section: Option<String>,
+}
+
+impl WikiReference {
+    /// Full url of the referenced article
+    pub fn url(&self) -> String {
+        let mut url = format!("https://osu.ppy.sh/wiki/{}/{}", self.locale, self.path);
+
+        if let Some(ref section) = self.section {
+            url.push('#');
+            url.push_str(section);
+        }
+
+        url
+    }
+}
+
+fn parse_heading(line: &str) -> Option<WikiSection> {
+    let level = line.bytes().take_while(|&b| b == b'#').count();
+
+    if !(1..=6).contains(&level) {
+        return None;
+    }
+
+    let title = line[level..].strip_prefix(' ')?.trim();
+
+    let (title, id) = match title.strip_suffix('}').and_then(|t| t.rsplit_once("{#")) {
+        Some((title, id)) => (title.trim_end(), id.to_owned()),
+        None => (title, slugify(title)),
+    };
+
+    Some(WikiSection {
+        content: String::new(),
+        id,
+        level: level as u8,
+        title: title.to_owned(),
+    })
+}
+
+fn slugify(title: &str) -> String {
+    let mut slug = String::with_capacity(title.len());
+
+    for c in title.chars().flat_map(char::to_lowercase) {
+        if c.is_alphanumeric() || c == '_' {
+            slug.push(c);
+        } else if (c == ' ' || c == '-') && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+
+    slug.trim_matches('-').to_owned()
+}
+
+/// Parses `[label]: url` lines
+fn parse_link_definition(line: &str) -> Option<WikiLink> {
+    let (label, rest) = line.strip_prefix('[')?.split_once("]:")?;
+    let url = rest.split_whitespace().next()?;
+
+    Some(WikiLink {
+        text: label.to_owned(),
+        url: url.trim_start_matches('<').trim_end_matches('>').to_owned(),
+    })
+}
+
+/// Parses `[text](url)` links, skipping images
+fn parse_inline_links(line: &str, links: &mut Vec<WikiLink>) {
+    let bytes = line.as_bytes();
+    let mut i = 0;
+
+    while let Some(offset) = line[i..].find('[') {
+        let start = i + offset;
+        i = start + 1;
+
+        let is_image = start > 0 && bytes[start - 1] == b'!';
+        let mut depth = 1;
+        let mut text_end = None;
+
+        for (j, &b) in bytes.iter().enumerate().skip(start + 1) {
+            match b {
+                b'[' => depth += 1,
+                b']' => {
+                    depth -= 1;
+
+                    if depth == 0 {
+                        text_end = Some(j);
+
+                        break;
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let text_end = match text_end {
+            Some(end) if bytes.get(end + 1) == Some(&b'(') => end,
+            _ => continue,
+        };
+
+        let url_end = match line[text_end + 2..].find(')') {
+            Some(offset) => text_end + 2 + offset,
+            None => continue,
+        };
+
+        let text = &line[start + 1..text_end];
+        let url = line[text_end + 2..url_end].split_whitespace().next();
+
+        if let Some(url) = url.filter(|_| !is_image) {
+            // Nested links e.g. images inside links
+            parse_inline_links(text, links);
+
+            links.push(WikiLink {
+                text: text.to_owned(),
+                url: url.to_owned(),
+            });
+        }
+
+        i = url_end + 1;
+    }
+}
+
+fn join_path(base: &str, relative: &str) -> String {
+    let mut segments: Vec<_> = base.split('/').filter(|s| !s.is_empty()).collect();
+
+    for segment in relative.split('/') {
+        match segment {
+            "" | "." => {}
+            ".." => {
+                segments.pop();
+            }
+            _ => segments.push(segment),
+        }
+    }
+
+    segments.join("/")
+}
+
+/// Whether the path segment is a locale rather than part of an article's path
+fn is_locale(s: &str) -> bool {
+    LOCALES.iter().any(|locale| locale.eq_ignore_ascii_case(s))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn page(markdown: &str) -> WikiPage {
+        WikiPage {
+            available_locales: vec!["en".to_owned(), "de".to_owned(), "zh-tw".to_owned()],
+            layout: "markdown_page".to_owned(),
+            locale: "de".to_owned(),
+            markdown: markdown.to_owned(),
+            path: "Hit_object".to_owned(),
+            subtitle: None,
+            tags: Vec::new(),
+            title: "Hit object".to_owned(),
+        }
+    }
+
+    #[test]
+    fn parse_sections() {
+        let markdown = "Intro with [a link](https://osu.ppy.sh).\n\n\
+            ## Circle {#hit-circle}\n\nSome ![image](img/circle.png) text.\n\n\
+            ```\n# not a heading\n```\n\n\
+            ### Sub section\n\nMore text";
+
+        let content = page(markdown).parse();
+
+        assert_eq!(content.intro, "Intro with [a link](https://osu.ppy.sh).");
+        assert_eq!(content.sections.len(), 2);
+        assert_eq!(content.sections[0].id, "hit-circle");
+        assert_eq!(content.sections[0].title, "Circle");
+        assert_eq!(
+            content.sections[0].content,
+            "Some ![image](img/circle.png) text.\n\n```\n# not a heading\n```"
+        );
+        assert_eq!(content.sections[1].id, "sub-section");
+        assert_eq!(content.sections[1].level, 3);
+        assert_eq!(content.links.len(), 1);
+    }
+
+    #[test]
+    fn parse_references() {
+        let markdown = "See [sliders](Slider#ticks), [modes](/wiki/Game_mode), \
+            [the chinese article][zh], and [the japanese one](/wiki/ja/Beatmap).\n\n\
+            [zh]: /wiki/zh-tw/Beatmap \"Beatmap\"";
+
+        let content = page(markdown).parse_with_locale("fr");
+
+        let expected = vec![
+            WikiReference {
+                locale: "en".to_owned(),
+                path: "Hit_object/Slider".to_owned(),
+                section: Some("ticks".to_owned()),
+            },
+            WikiReference {
+                locale: "en".to_owned(),
+                path: "Game_mode".to_owned(),
+                section: None,
+            },
+            WikiReference {
+                locale: "ja".to_owned(),
+                path: "Beatmap".to_owned(),
+                section: None,
+            },
+            WikiReference {
+                locale: "zh-tw".to_owned(),
+                path: "Beatmap".to_owned(),
+                section: None,
+            },
+        ];
+
+        assert_eq!(content.references, expected);
+        assert_eq!(
+            expected[0].url(),
+            "https://osu.ppy.sh/wiki/en/Hit_object/Slider#ticks"
+        );
+    }
+}
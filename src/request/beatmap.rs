@@ -1,24 +1,31 @@
 use crate::{
+    error::OsuError,
     model::{
         beatmap::{
-            Beatmap, Beatmapset, BeatmapsetEvents, BeatmapsetSearchResult, BeatmapsetSearchSort,
-            Genre, Language, RankStatus,
+            Beatmap, BeatmapPack, BeatmapPackType, BeatmapPacks, Beatmapset, BeatmapsetEventType,
+            BeatmapsetEvents, BeatmapsetSearchContinuation, BeatmapsetSearchResult,
+            BeatmapsetSearchSort, Genre, Language, RankStatus,
         },
         beatmap_::{
             BeatmapDifficultyAttributes, BeatmapDifficultyAttributesWrapper, Beatmaps,
-            SearchRankStatus,
+            BeatmapsetFavouriteCount, BeatmapsetSearchParameters, SearchRankStatus,
         },
-        score_::{BeatmapScores, BeatmapUserScore, Score, Scores},
-        Cursor, GameMode, GameMods,
+        recent_event::EventSort,
+        score_::{BeatmapScores, BeatmapUserScore, LeaderboardType, Score, Scores},
+        Cursor, GameMode, GameMods, GameModsIntermediate, Grade,
     },
     prelude::BeatmapCompact,
-    request::{Pending, Query, Request},
+    request::{Pending, Query, Request, ResponseStream},
     routing::Route,
-    Osu,
+    Osu, OsuResult,
 };
 
-use futures::future::TryFutureExt;
-use std::{fmt::Write, mem};
+use futures::{
+    future::{self, FutureExt, TryFutureExt},
+    stream::{self, Stream, StreamExt, TryStreamExt},
+};
+use std::{collections::HashMap, fmt::Write, mem};
+use time::{format_description::well_known::Rfc3339, OffsetDateTime, UtcOffset};
 
 use super::Body;
 #[cfg(feature = "cache")]
@@ -99,6 +106,84 @@ impl<'a> GetBeatmap<'a> {
 
 poll_req!(GetBeatmap => Beatmap);
 
+/// Resolve beatmap checksums to [`Beatmap`](crate::model::beatmap::Beatmap)s.
+///
+/// Checksums are lowercased and duplicates are only requested once.
+/// The individual requests run concurrently within the ratelimit and
+/// each checksum is mapped to the result of its request.
+/// Checksums that don't belong to a map are mapped to `Ok(None)`.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct GetBeatmapsByChecksums<'a> {
+    fut: Option<Pending<'a, HashMap<String, OsuResult<Option<Beatmap>>>>>,
+    osu: &'a Osu,
+    checksums: Vec<String>,
+}
+
+impl<'a> GetBeatmapsByChecksums<'a> {
+    pub(crate) fn new<I, S>(osu: &'a Osu, checksums: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let checksums = checksums
+            .into_iter()
+            .map(|checksum| {
+                let mut checksum = checksum.into();
+                checksum.make_ascii_lowercase();
+
+                checksum
+            })
+            .collect();
+
+        Self {
+            fut: None,
+            osu,
+            checksums,
+        }
+    }
+
+    fn start(&mut self) -> Pending<'a, HashMap<String, OsuResult<Option<Beatmap>>>> {
+        let osu = self.osu;
+        let mut checksums = mem::take(&mut self.checksums);
+        checksums.sort_unstable();
+        checksums.dedup();
+
+        let reqs = checksums.into_iter().map(move |checksum| {
+            #[cfg(feature = "cache")]
+            let missing = osu.is_missing_checksum(&checksum);
+
+            #[cfg(not(feature = "cache"))]
+            let missing = false;
+
+            let req = (!missing).then(|| GetBeatmap::new(osu).checksum(checksum.as_str()));
+
+            async move {
+                let map = match req {
+                    Some(req) => match req.await {
+                        Ok(map) => Ok(Some(map)),
+                        Err(OsuError::NotFound) => {
+                            #[cfg(feature = "cache")]
+                            osu.insert_missing_checksum(checksum.clone());
+
+                            Ok(None)
+                        }
+                        Err(err) => Err(err),
+                    },
+                    None => Ok(None),
+                };
+
+                (checksum, map)
+            }
+        });
+
+        let fut = future::join_all(reqs).map(|maps| Ok(maps.into_iter().collect()));
+
+        Box::pin(fut)
+    }
+}
+
+poll_req!(GetBeatmapsByChecksums => HashMap<String, OsuResult<Option<Beatmap>>>);
+
 /// Get a vec of [`BeatmapCompact`](crate::model::beatmap::BeatmapCompact) by their map ids.
 #[must_use = "futures do nothing unless you `.await` or poll them"]
 pub struct GetBeatmaps<'a> {
@@ -210,33 +295,374 @@ impl<'a> GetBeatmapDifficultyAttributes<'a> {
 
 poll_req!(GetBeatmapDifficultyAttributes => BeatmapDifficultyAttributes);
 
-/// Get top scores of a beatmap by its id in form of a
-/// vec of [`Score`](crate::model::score::Score)s.
+/// Get [`BeatmapDifficultyAttributes`] of a map for multiple mod combinations at once.
+///
+/// Mod combinations that only differ in mods which are irrelevant for the
+/// difficulty attributes share a single request, e.g. `HD` and `NM`
+/// or `DT` and `NC`. A bounded amount of requests run concurrently within the ratelimit.
+///
+/// The resulting map contains an entry for each specified combination.
+/// If no mods are specified, only the `NM` attributes are retrieved.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct GetBeatmapDifficultyAttributesBatch<'a> {
+    fut: Option<Pending<'a, HashMap<GameMods, BeatmapDifficultyAttributes>>>,
+    osu: &'a Osu,
+    map_id: u32,
+    mode: Option<GameMode>,
+    mods: Vec<GameMods>,
+}
+
+impl<'a> GetBeatmapDifficultyAttributesBatch<'a> {
+    pub(crate) fn new(osu: &'a Osu, map_id: u32, mods: impl IntoIterator<Item = GameMods>) -> Self {
+        Self {
+            fut: None,
+            osu,
+            map_id,
+            mode: None,
+            mods: mods.into_iter().collect(),
+        }
+    }
+
+    /// Specify the mode
+    #[inline]
+    pub fn mode(mut self, mode: GameMode) -> Self {
+        self.mode = Some(mode);
+
+        self
+    }
+
+    fn start(&mut self) -> Pending<'a, HashMap<GameMods, BeatmapDifficultyAttributes>> {
+        let map_id = self.map_id;
+        let mods = mem::take(&mut self.mods);
+
+        let fut = difficulty_attributes_batch(self.osu, vec![map_id], self.mode, mods)
+            .map_ok(move |mut attrs| attrs.remove(&map_id).unwrap_or_default());
+
+        Box::pin(fut)
+    }
+}
+
+poll_req!(GetBeatmapDifficultyAttributesBatch => HashMap<GameMods, BeatmapDifficultyAttributes>);
+
+/// Get [`BeatmapDifficultyAttributes`] of multiple maps for multiple mod combinations at once.
+///
+/// Mod combinations that only differ in mods which are irrelevant for the
+/// difficulty attributes share a single request per map.
+/// A bounded amount of requests run concurrently within the ratelimit.
+///
+/// The resulting map contains an entry for each specified map id which in turn contains
+/// an entry for each specified combination.
+/// If no mods are specified, only the `NM` attributes are retrieved.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct GetBeatmapsDifficultyAttributes<'a> {
+    fut: Option<Pending<'a, HashMap<u32, HashMap<GameMods, BeatmapDifficultyAttributes>>>>,
+    osu: &'a Osu,
+    map_ids: Vec<u32>,
+    mode: Option<GameMode>,
+    mods: Vec<GameMods>,
+}
+
+impl<'a> GetBeatmapsDifficultyAttributes<'a> {
+    pub(crate) fn new(
+        osu: &'a Osu,
+        map_ids: impl IntoIterator<Item = u32>,
+        mods: impl IntoIterator<Item = GameMods>,
+    ) -> Self {
+        Self {
+            fut: None,
+            osu,
+            map_ids: map_ids.into_iter().collect(),
+            mode: None,
+            mods: mods.into_iter().collect(),
+        }
+    }
+
+    /// Specify the mode
+    #[inline]
+    pub fn mode(mut self, mode: GameMode) -> Self {
+        self.mode = Some(mode);
+
+        self
+    }
+
+    fn start(
+        &mut self,
+    ) -> Pending<'a, HashMap<u32, HashMap<GameMods, BeatmapDifficultyAttributes>>> {
+        let map_ids = mem::take(&mut self.map_ids);
+        let mods = mem::take(&mut self.mods);
+
+        difficulty_attributes_batch(self.osu, map_ids, self.mode, mods)
+    }
+}
+
+poll_req!(GetBeatmapsDifficultyAttributes => HashMap<u32, HashMap<GameMods, BeatmapDifficultyAttributes>>);
+
+/// Maximum amount of difficulty attribute requests that are in flight at once
+/// so that large batches don't drain the ratelimiter in one go.
+const DIFFICULTY_ATTRIBUTES_CONCURRENCY: usize = 8;
+
+fn difficulty_attributes_batch(
+    osu: &Osu,
+    mut map_ids: Vec<u32>,
+    mode: Option<GameMode>,
+    mut mods: Vec<GameMods>,
+) -> Pending<'_, HashMap<u32, HashMap<GameMods, BeatmapDifficultyAttributes>>> {
+    map_ids.sort_unstable();
+    map_ids.dedup();
+
+    if mods.is_empty() {
+        mods.push(GameMods::NoMod);
+    }
+
+    let keys = star_mods_keys(&mods, mode);
+
+    let reqs: Vec<_> = map_ids
+        .iter()
+        .flat_map(|&map_id| keys.iter().map(move |&key| (map_id, key)))
+        .map(|(map_id, key)| {
+            let req = GetBeatmapDifficultyAttributes::new(osu, map_id).mods(key);
+
+            let req = match mode {
+                Some(mode) => req.mode(mode),
+                None => req,
+            };
+
+            req.map_ok(move |attrs| ((map_id, key), attrs))
+        })
+        .collect();
+
+    let fut = stream::iter(reqs)
+        .buffer_unordered(DIFFICULTY_ATTRIBUTES_CONCURRENCY)
+        .try_collect()
+        .map_ok(move |attrs| expand_star_mods(&map_ids, &mods, mode, &attrs));
+
+    Box::pin(fut)
+}
+
+/// Reduce the mods to those that the osu!api considers for difficulty attributes.
+///
+/// `DT`, `HT`, `HR`, and `EZ` are always relevant. For osu!standard, `FL` and `TD`
+/// are relevant as well, and `HD` too if `FL` is enabled. For osu!mania, key mods
+/// are relevant for converted maps.
+///
+/// If the mode is not known, the osu!standard mods are kept
+/// in case the map turns out to be osu!standard.
+fn star_mods(mods: GameMods, mode: Option<GameMode>) -> GameMods {
+    let mut relevant =
+        GameMods::DoubleTime | GameMods::HalfTime | GameMods::HardRock | GameMods::Easy;
+
+    match mode {
+        Some(GameMode::Osu) | None => {
+            relevant |= GameMods::Flashlight | GameMods::TouchDevice;
+
+            if mods.contains(GameMods::Flashlight) {
+                relevant |= GameMods::Hidden;
+            }
+        }
+        Some(GameMode::Mania) => {
+            relevant |= GameMods::Key1
+                | GameMods::Key2
+                | GameMods::Key3
+                | GameMods::Key4
+                | GameMods::Key5
+                | GameMods::Key6
+                | GameMods::Key7
+                | GameMods::Key8
+                | GameMods::Key9
+                | GameMods::KeyCoop;
+        }
+        Some(GameMode::Taiko) | Some(GameMode::Catch) => {}
+    }
+
+    mods & relevant
+}
+
+/// Deduplicated mods for which difficulty attributes need to be requested.
+fn star_mods_keys(mods: &[GameMods], mode: Option<GameMode>) -> Vec<GameMods> {
+    let mut keys: Vec<_> = mods.iter().map(|&m| star_mods(m, mode)).collect();
+    keys.sort_unstable();
+    keys.dedup();
+
+    keys
+}
+
+/// Assign the attributes of each requested key to all mods that reduce to it.
+fn expand_star_mods<T: Clone>(
+    map_ids: &[u32],
+    mods: &[GameMods],
+    mode: Option<GameMode>,
+    attrs: &HashMap<(u32, GameMods), T>,
+) -> HashMap<u32, HashMap<GameMods, T>> {
+    map_ids
+        .iter()
+        .map(|&map_id| {
+            let map_attrs = mods
+                .iter()
+                .filter_map(|&m| {
+                    let key = star_mods(m, mode);
+
+                    attrs.get(&(map_id, key)).map(|attrs| (m, attrs.to_owned()))
+                })
+                .collect();
+
+            (map_id, map_attrs)
+        })
+        .collect()
+}
+
+/// Get a [`BeatmapPack`](crate::model::beatmap::BeatmapPack) including its
+/// [`BeatmapsetCompact`](crate::model::beatmap::BeatmapsetCompact)s.
+///
+/// If the client is authenticated through OAuth, the pack also contains
+/// the user's completion data.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct GetBeatmapPack<'a> {
+    fut: Option<Pending<'a, BeatmapPack>>,
+    osu: &'a Osu,
+    tag: Option<String>,
+    legacy_only: Option<bool>,
+}
+
+impl<'a> GetBeatmapPack<'a> {
+    #[inline]
+    pub(crate) fn new(osu: &'a Osu, tag: impl Into<String>) -> Self {
+        Self {
+            fut: None,
+            osu,
+            tag: Some(tag.into()),
+            legacy_only: None,
+        }
+    }
+
+    /// Whether only scores set on osu!stable should count towards the user's completion
+    #[inline]
+    pub fn legacy_only(mut self, legacy_only: bool) -> Self {
+        self.legacy_only.replace(legacy_only);
+
+        self
+    }
+
+    fn start(&mut self) -> Pending<'a, BeatmapPack> {
+        #[cfg(feature = "metrics")]
+        self.osu.metrics.beatmap_pack.inc();
+
+        let mut query = Query::new();
+
+        if let Some(legacy_only) = self.legacy_only {
+            query.push("legacy_only", legacy_only as u8);
+        }
+
+        let route = Route::GetBeatmapPack {
+            tag: self.tag.take().unwrap(),
+        };
+
+        let req = Request::with_query(route, query);
+
+        Box::pin(self.osu.request(req))
+    }
+}
+
+poll_req!(GetBeatmapPack => BeatmapPack);
+
+/// Get [`BeatmapPacks`](crate::model::beatmap::BeatmapPacks).
+///
+/// If no pack type is specified, it defaults to standard packs.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct GetBeatmapPacks<'a> {
+    fut: Option<Pending<'a, BeatmapPacks>>,
+    osu: &'a Osu,
+    pack_type: Option<BeatmapPackType>,
+    cursor: Option<Cursor>,
+}
+
+impl<'a> GetBeatmapPacks<'a> {
+    #[inline]
+    pub(crate) fn new(osu: &'a Osu) -> Self {
+        Self {
+            fut: None,
+            osu,
+            pack_type: None,
+            cursor: None,
+        }
+    }
+
+    /// Specify the type of packs
+    #[inline]
+    pub fn pack_type(mut self, pack_type: BeatmapPackType) -> Self {
+        self.pack_type.replace(pack_type);
+
+        self
+    }
+
+    #[inline]
+    #[cfg(not(feature = "rkyv"))]
+    pub(crate) fn cursor(mut self, cursor: Cursor) -> Self {
+        self.cursor.replace(cursor);
+
+        self
+    }
+
+    fn start(&mut self) -> Pending<'a, BeatmapPacks> {
+        #[cfg(feature = "metrics")]
+        self.osu.metrics.beatmap_packs.inc();
+
+        let mut query = Query::new();
+
+        if let Some(pack_type) = self.pack_type {
+            query.push("type", pack_type);
+        }
+
+        if let Some(cursor) = self.cursor.take() {
+            cursor.push_to_query(&mut query);
+        }
+
+        let req = Request::with_query(Route::GetBeatmapPacks, query);
+        let pack_type = self.pack_type;
+
+        let fut = self
+            .osu
+            .request::<BeatmapPacks>(req)
+            .map_ok(move |mut packs| {
+                packs.pack_type = pack_type;
+
+                packs
+            });
+
+        Box::pin(fut)
+    }
+}
+
+poll_req!(GetBeatmapPacks => BeatmapPacks);
+
+/// Get the leaderboard of a beatmap by its id in form of
+/// [`BeatmapScores`](crate::model::score::BeatmapScores).
 #[must_use = "futures do nothing unless you `.await` or poll them"]
 pub struct GetBeatmapScores<'a> {
-    fut: Option<Pending<'a, Vec<Score>>>,
+    fut: Option<Pending<'a, BeatmapScores>>,
     osu: &'a Osu,
     map_id: u32,
-    score_type: Option<&'static str>,
+    leaderboard_type: Option<LeaderboardType>,
     mode: Option<GameMode>,
-    mods: Option<GameMods>,
-    // ! Currently not working
-    // limit: Option<u32>,
-    // offset: Option<u32>,
+    mods: Option<GameModsIntermediate>,
+    limit: Option<u32>,
+    legacy_only: Option<bool>,
 }
 
 impl<'a> GetBeatmapScores<'a> {
+    /// The maximum amount of scores the API provides for a leaderboard
+    pub const MAX_LIMIT: u32 = 100;
+
     #[inline]
     pub(crate) fn new(osu: &'a Osu, map_id: u32) -> Self {
         Self {
             fut: None,
             osu,
             map_id,
-            score_type: None, // TODO
+            leaderboard_type: None,
             mode: None,
             mods: None,
-            // limit: None,
-            // offset: None,
+            limit: None,
+            legacy_only: None,
         }
     }
 
@@ -248,65 +674,76 @@ impl<'a> GetBeatmapScores<'a> {
         self
     }
 
-    /// Specify the mods of the scores
-    #[deprecated = "Does not currently work since the API requires osu!supporter for this feature"]
+    /// Only include scores with exactly the given mods.
+    ///
+    /// Requires osu!supporter of the authenticated user.
+    /// Passing [`GameMods::NoMod`] only includes scores without mods.
     #[inline]
-    pub fn mods(mut self, mods: GameMods) -> Self {
-        self.mods.replace(mods);
+    pub fn mods(mut self, mods: impl Into<GameModsIntermediate>) -> Self {
+        self.mods.replace(mods.into());
 
         self
     }
 
-    /// Specify the score type of the scores
-    #[deprecated = "Does not currently work since the API requires osu!supporter for this feature"]
+    /// Specify the kind of leaderboard, defaults to the global leaderboard.
+    ///
+    /// All but the global leaderboard require osu!supporter of the authenticated user.
     #[inline]
-    pub fn score_type(mut self, score_type: &'static str) -> Self {
-        self.score_type.replace(score_type);
+    pub fn leaderboard_type(mut self, leaderboard_type: LeaderboardType) -> Self {
+        self.leaderboard_type.replace(leaderboard_type);
 
         self
     }
 
-    // #[inline]
-    // pub fn limit(mut self, limit: u32) -> Self {
-    //     self.limit.replace(limit);
+    /// Limit the amount of scores, at most [`MAX_LIMIT`](GetBeatmapScores::MAX_LIMIT).
+    ///
+    /// Defaults to 50.
+    #[inline]
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.limit.replace(limit.min(Self::MAX_LIMIT));
 
-    //     self
-    // }
+        self
+    }
 
-    // #[inline]
-    // pub fn offset(mut self, offset: u32) -> Self {
-    //     self.offset.replace(offset);
+    /// Whether only scores set on osu!stable should be included
+    #[inline]
+    pub fn legacy_only(mut self, legacy_only: bool) -> Self {
+        self.legacy_only.replace(legacy_only);
 
-    //     self
-    // }
+        self
+    }
 
-    fn start(&mut self) -> Pending<'a, Vec<Score>> {
+    fn start(&mut self) -> Pending<'a, BeatmapScores> {
         #[cfg(feature = "metrics")]
         self.osu.metrics.beatmap_scores.inc();
 
         let mut query = Query::new();
 
         if let Some(mode) = self.mode {
-            query.push("mode", &mode.to_string());
+            query.push("mode", mode);
         }
 
-        if let Some(mods) = self.mods {
-            for m in mods {
-                query.push("mods[]", &m.to_string());
+        if let Some(ref mods) = self.mods {
+            if mods.is_empty() {
+                query.push("mods[]", "NM");
+            }
+
+            for gamemod in mods {
+                query.push("mods[]", &gamemod.acronym);
             }
         }
 
-        if let Some(score_type) = self.score_type {
-            query.push("type", &score_type);
+        if let Some(leaderboard_type) = self.leaderboard_type {
+            query.push("type", leaderboard_type);
         }
 
-        // if let Some(limit) = self.limit {
-        //     query.push("limit", limit);
-        // }
+        if let Some(limit) = self.limit {
+            query.push("limit", limit);
+        }
 
-        // if let Some(offset) = self.offset {
-        //     query.push("offset", offset);
-        // }
+        if let Some(legacy_only) = self.legacy_only {
+            query.push("legacy_only", legacy_only as u8);
+        }
 
         let route = Route::GetBeatmapScores {
             map_id: self.map_id,
@@ -314,11 +751,13 @@ impl<'a> GetBeatmapScores<'a> {
 
         let req = Request::with_query(route, query);
         let osu = self.osu;
-        let fut = osu.request::<BeatmapScores>(req).map_ok(|s| s.scores);
+        let fut = osu.request::<BeatmapScores>(req);
 
         #[cfg(feature = "cache")]
-        let fut = fut.inspect_ok(move |scores| {
-            for score in scores.iter() {
+        let fut = fut.inspect_ok(move |leaderboard| {
+            let user_score = leaderboard.user_score.as_ref().map(|score| &score.score);
+
+            for score in leaderboard.scores.iter().chain(user_score) {
                 if let Some(ref user) = score.user {
                     osu.update_cache(user.user_id, &user.username);
                 }
@@ -329,7 +768,7 @@ impl<'a> GetBeatmapScores<'a> {
     }
 }
 
-poll_req!(GetBeatmapScores => Vec<Score>);
+poll_req!(GetBeatmapScores => BeatmapScores);
 
 /// Get [`BeatmapUserScore`](crate::model::score::BeatmapUserScore)
 /// of a user on a beatmap by the user's and the map's id.
@@ -586,6 +1025,168 @@ impl<'a> GetBeatmapset<'a> {
 
 poll_req!(GetBeatmapset => Beatmapset);
 
+/// Download the `.osz` archive of a [`Beatmapset`](crate::model::beatmap::Beatmapset).
+///
+/// Resolves to a [`ResponseStream`] which streams the archive in chunks
+/// instead of buffering it entirely.
+///
+/// Note that this endpoint is not part of the documented API
+/// and may not be available for all kinds of authorization.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct GetBeatmapsetDownload<'a> {
+    fut: Option<Pending<'a, ResponseStream>>,
+    osu: &'a Osu,
+    mapset_id: u32,
+    no_video: bool,
+}
+
+impl<'a> GetBeatmapsetDownload<'a> {
+    #[inline]
+    pub(crate) fn new(osu: &'a Osu, mapset_id: u32) -> Self {
+        Self {
+            fut: None,
+            osu,
+            mapset_id,
+            no_video: false,
+        }
+    }
+
+    /// Specify whether the archive should exclude the video
+    #[inline]
+    pub fn no_video(mut self, no_video: bool) -> Self {
+        self.no_video = no_video;
+
+        self
+    }
+
+    fn start(&mut self) -> Pending<'a, ResponseStream> {
+        #[cfg(feature = "metrics")]
+        self.osu.metrics.beatmapset_download.inc();
+
+        let mut query = Query::new();
+
+        if self.no_video {
+            query.push("noVideo", 1);
+        }
+
+        let route = Route::GetBeatmapsetDownload {
+            mapset_id: self.mapset_id,
+        };
+
+        let req = Request::with_query(route, query);
+
+        let fut = self.osu.request_stream(req).map_ok(ResponseStream::new);
+
+        Box::pin(fut)
+    }
+}
+
+poll_req!(GetBeatmapsetDownload => ResponseStream);
+
+/// Favourite or unfavourite a [`Beatmapset`](crate::model::beatmap::Beatmapset)
+/// for the authenticated user.
+///
+/// Resolves to the mapset's updated `favourite_count`.
+///
+/// Note that the client has to be initialized through the OAuth process
+/// in order for this endpoint to not return an error.
+///
+/// See [`OsuBuilder::with_authorization`](crate::OsuBuilder::with_authorization).
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct FavouriteBeatmapset<'a> {
+    fut: Option<Pending<'a, u32>>,
+    osu: &'a Osu,
+    mapset_id: u32,
+    favourite: bool,
+}
+
+impl<'a> FavouriteBeatmapset<'a> {
+    #[inline]
+    pub(crate) fn new(osu: &'a Osu, mapset_id: u32, favourite: bool) -> Self {
+        Self {
+            fut: None,
+            osu,
+            mapset_id,
+            favourite,
+        }
+    }
+
+    fn start(&mut self) -> Pending<'a, u32> {
+        #[cfg(feature = "metrics")]
+        self.osu.metrics.beatmapset_favourite.inc();
+
+        let action = if self.favourite {
+            "favourite"
+        } else {
+            "unfavourite"
+        };
+
+        let mut body = Body::default();
+        body.push_with_quotes("action", action);
+
+        let route = Route::PostBeatmapsetFavourite {
+            mapset_id: self.mapset_id,
+        };
+
+        let req = Request::with_body(route, body);
+
+        let fut = self
+            .osu
+            .request::<BeatmapsetFavouriteCount>(req)
+            .map_ok(|count| count.favourite_count);
+
+        Box::pin(fut)
+    }
+}
+
+poll_req!(FavouriteBeatmapset => u32);
+
+/// Watch or unwatch the discussion of a
+/// [`Beatmapset`](crate::model::beatmap::Beatmapset) for the authenticated user.
+///
+/// Watching a mapset means receiving notifications about new discussion posts.
+///
+/// Note that the client has to be initialized through the OAuth process
+/// in order for this endpoint to not return an error.
+///
+/// See [`OsuBuilder::with_authorization`](crate::OsuBuilder::with_authorization).
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct WatchBeatmapset<'a> {
+    fut: Option<Pending<'a, ()>>,
+    osu: &'a Osu,
+    mapset_id: u32,
+    watch: bool,
+}
+
+impl<'a> WatchBeatmapset<'a> {
+    #[inline]
+    pub(crate) fn new(osu: &'a Osu, mapset_id: u32, watch: bool) -> Self {
+        Self {
+            fut: None,
+            osu,
+            mapset_id,
+            watch,
+        }
+    }
+
+    fn start(&mut self) -> Pending<'a, ()> {
+        #[cfg(feature = "metrics")]
+        self.osu.metrics.beatmapset_watch.inc();
+
+        let route = Route::UpdateBeatmapsetWatch {
+            mapset_id: self.mapset_id,
+            watch: self.watch,
+        };
+
+        let req = Request::new(route);
+        let fut = self.osu.request_raw(req).map_ok(|_| ());
+
+        Box::pin(fut)
+    }
+}
+
+poll_req!(WatchBeatmapset => ());
+
 /// Get a [`Beatmapset`](crate::model::beatmap::Beatmapset) from a beatmap ID.
 #[must_use = "futures do nothing unless you `.await` or poll them"]
 pub struct GetBeatmapsetFromMapId<'a> {
@@ -624,23 +1225,182 @@ impl<'a> GetBeatmapsetFromMapId<'a> {
 poll_req!(GetBeatmapsetFromMapId => Beatmapset);
 
 /// Get a [`BeatmapsetEvents`](crate::model::beatmap::BeatmapsetEvents) struct.
+///
+/// To walk through all pages, use
+/// [`into_stream`](crate::request::GetBeatmapsetEvents::into_stream).
 #[must_use = "futures do nothing unless you `.await` or poll them"]
 pub struct GetBeatmapsetEvents<'a> {
     fut: Option<Pending<'a, BeatmapsetEvents>>,
     osu: &'a Osu,
+    user_id: Option<u32>,
+    types: Option<Vec<BeatmapsetEventType>>,
+    min_date: Option<OffsetDateTime>,
+    max_date: Option<OffsetDateTime>,
+    sort: Option<EventSort>,
+    limit: Option<usize>,
+    page: Option<usize>,
 }
 
 impl<'a> GetBeatmapsetEvents<'a> {
     #[inline]
     pub(crate) fn new(osu: &'a Osu) -> Self {
-        Self { fut: None, osu }
+        Self {
+            fut: None,
+            osu,
+            user_id: None,
+            types: None,
+            min_date: None,
+            max_date: None,
+            sort: None,
+            limit: None,
+            page: None,
+        }
+    }
+
+    /// Only include events caused by the user
+    #[inline]
+    pub fn user(mut self, user_id: u32) -> Self {
+        self.user_id.replace(user_id);
+
+        self
+    }
+
+    /// Only include events of the given types
+    #[inline]
+    pub fn types(mut self, types: &[BeatmapsetEventType]) -> Self {
+        self.types.replace(types.to_owned());
+
+        self
+    }
+
+    /// Only include events that happened after the given date
+    #[inline]
+    pub fn min_date(mut self, min_date: OffsetDateTime) -> Self {
+        self.min_date.replace(min_date);
+
+        self
+    }
+
+    /// Only include events that happened before the given date
+    #[inline]
+    pub fn max_date(mut self, max_date: OffsetDateTime) -> Self {
+        self.max_date.replace(max_date);
+
+        self
+    }
+
+    /// Specify the order of the events
+    #[inline]
+    pub fn sort(mut self, sort: EventSort) -> Self {
+        self.sort.replace(sort);
+
+        self
+    }
+
+    /// Limit the amount of events per page
+    #[inline]
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit.replace(limit);
+
+        self
+    }
+
+    /// Specify the page, starting at 1
+    #[inline]
+    pub fn page(mut self, page: usize) -> Self {
+        self.page.replace(page);
+
+        self
+    }
+
+    /// Turn the request into a stream that yields one page after the other,
+    /// starting at the specified page or the first one.
+    ///
+    /// The stream ends once a page contains fewer events than the
+    /// specified limit or no events at all.
+    pub fn into_stream(self) -> impl Stream<Item = OsuResult<BeatmapsetEvents>> + 'a {
+        let Self {
+            osu,
+            user_id,
+            types,
+            min_date,
+            max_date,
+            sort,
+            limit,
+            page,
+            ..
+        } = self;
+
+        stream::try_unfold(page.or(Some(1)), move |page| {
+            let req = page.map(|page| Self {
+                fut: None,
+                osu,
+                user_id,
+                types: types.clone(),
+                min_date,
+                max_date,
+                sort,
+                limit,
+                page: Some(page),
+            });
+
+            async move {
+                let (req, page) = match req.zip(page) {
+                    Some(tuple) => tuple,
+                    None => return Ok(None),
+                };
+
+                let events = req.await?;
+                let len = events.events.len();
+
+                let next_page = if len == 0 || matches!(limit, Some(limit) if len < limit) {
+                    None
+                } else {
+                    Some(page + 1)
+                };
+
+                Ok(Some((events, next_page)))
+            }
+        })
     }
 
     fn start(&mut self) -> Pending<'a, BeatmapsetEvents> {
         #[cfg(feature = "metrics")]
         self.osu.metrics.beatmapset_events.inc();
 
-        let req = Request::new(Route::GetBeatmapsetEvents);
+        let mut query = Query::new();
+
+        if let Some(user_id) = self.user_id {
+            query.push("user", user_id);
+        }
+
+        if let Some(ref types) = self.types {
+            for event_type in types {
+                query.push("types[]", event_type);
+            }
+        }
+
+        if let Some(min_date) = self.min_date {
+            query.push("min_date", format_date(min_date));
+        }
+
+        if let Some(max_date) = self.max_date {
+            query.push("max_date", format_date(max_date));
+        }
+
+        if let Some(sort) = self.sort {
+            query.push("sort", sort);
+        }
+
+        if let Some(limit) = self.limit {
+            query.push("limit", limit);
+        }
+
+        if let Some(page) = self.page {
+            query.push("page", page);
+        }
+
+        let req = Request::with_query(Route::GetBeatmapsetEvents, query);
 
         Box::pin(self.osu.request(req))
     }
@@ -648,6 +1408,21 @@ impl<'a> GetBeatmapsetEvents<'a> {
 
 poll_req!(GetBeatmapsetEvents => BeatmapsetEvents);
 
+/// Format a date for a query.
+///
+/// Rfc3339 only supports the years 0 through 9999 so dates beyond
+/// are clamped which doesn't change the outcome of a filter.
+fn format_date(date: OffsetDateTime) -> String {
+    // Formatting as UTC to prevent a '+' in the query
+    let date = date.to_offset(UtcOffset::UTC);
+
+    match date.format(&Rfc3339) {
+        Ok(formatted) => formatted,
+        Err(_) if date.year() < 0 => "0000-01-01T00:00:00Z".to_owned(),
+        Err(_) => "9999-12-31T23:59:59Z".to_owned(),
+    }
+}
+
 /// Get a [`BeatmapsetSearchResult`](crate::model::beatmap::BeatmapsetSearchResult)
 /// struct containing the first page of maps that fit the search query.
 ///
@@ -658,6 +1433,9 @@ poll_req!(GetBeatmapsetEvents => BeatmapsetEvents);
 /// - language: any
 /// - extra: does neither contain "have video" nor "have storyboard"
 /// - nsfw: allowed
+/// - played: any
+/// - rank achieved: any
+/// - general: neither recommended difficulties, converts, followed mappers, spotlights, nor featured artists
 /// - sort: by relevance, descending
 ///
 /// The contained [`Beatmapset`](crate::model::beatmap::Beatmapset)s will have the
@@ -666,32 +1444,34 @@ poll_req!(GetBeatmapsetEvents => BeatmapsetEvents);
 ///
 /// The search query allows the following options to be specified: `ar`, `artist`,
 /// `bpm`, `created`, `creator`, `cs`, `dr` (hp drain rate), `keys`, `length`,
-/// `ranked`, `stars`, and `status`.
+/// `ranked`, `stars`, and `status`. Instead of writing the query by hand, consider using
+/// [`SearchQuery`](crate::model::beatmap::SearchQuery).
 ///
 /// ## Example
 ///
 /// ```
+/// use rosu_v2::model::beatmap::{RankStatus, SearchQuery};
+///
 /// // Search for mapsets from Sotarks that have a map with no more than AR 9.
 /// let query = "creator=sotarks ar<9";
 ///
 /// // Loved mapsets from Camellia including at least one map above 8 stars
 /// let query = "status=loved artist=camellia stars>8";
+///
+/// // The same query in its typed form
+/// let query = SearchQuery::new()
+///     .status(RankStatus::Loved)
+///     .artist("camellia")
+///     .stars(8.0..);
 /// ```
 #[must_use = "futures do nothing unless you `.await` or poll them"]
 pub struct GetBeatmapsetSearch<'a> {
     fut: Option<Pending<'a, BeatmapsetSearchResult>>,
     osu: &'a Osu,
-    query: Option<String>,
-    mode: Option<u8>,
-    status: Option<SearchRankStatus>,
-    genre: Option<u8>,
-    language: Option<u8>,
-    video: bool,
-    storyboard: bool,
-    nsfw: bool,
-    sort: Option<BeatmapsetSearchSort>,
-    descending: bool,
+    params: BeatmapsetSearchParameters,
+    sort: bool,
     cursor: Option<Cursor>,
+    cursor_string: Option<String>,
 }
 
 impl<'a> GetBeatmapsetSearch<'a> {
@@ -700,24 +1480,17 @@ impl<'a> GetBeatmapsetSearch<'a> {
         Self {
             fut: None,
             osu,
-            query: None,
-            mode: None,
-            status: None,
-            genre: None,
-            language: None,
-            video: false,
-            storyboard: false,
-            nsfw: true,
-            sort: None,
-            descending: true,
+            params: BeatmapsetSearchParameters::default(),
+            sort: false,
             cursor: None,
+            cursor_string: None,
         }
     }
 
-    /// Specify a search query.
+    /// Specify a search query, either as string or as [`SearchQuery`](crate::model::beatmap::SearchQuery).
     #[inline]
     pub fn query(mut self, query: impl Into<String>) -> Self {
-        self.query.replace(query.into());
+        self.params.query.replace(query.into());
 
         self
     }
@@ -725,7 +1498,7 @@ impl<'a> GetBeatmapsetSearch<'a> {
     /// Specify the mode for which the mapsets has to have at least one map.
     #[inline]
     pub fn mode(mut self, mode: GameMode) -> Self {
-        self.mode.replace(mode as u8);
+        self.params.mode.replace(mode as u8);
 
         self
     }
@@ -734,7 +1507,7 @@ impl<'a> GetBeatmapsetSearch<'a> {
     /// [`status`](crate::request::GetBeatmapsetSearch::status) method.
     #[inline]
     pub fn any_status(mut self) -> Self {
-        self.status.replace(SearchRankStatus::Any);
+        self.params.status.replace(SearchRankStatus::Any);
 
         self
     }
@@ -751,7 +1524,9 @@ impl<'a> GetBeatmapsetSearch<'a> {
             status = RankStatus::Pending;
         }
 
-        self.status.replace(SearchRankStatus::Specific(status));
+        self.params
+            .status
+            .replace(SearchRankStatus::Specific(status));
 
         self
     }
@@ -759,7 +1534,7 @@ impl<'a> GetBeatmapsetSearch<'a> {
     /// Specify a genre for the mapsets, defaults to `Any`.
     #[inline]
     pub fn genre(mut self, genre: Genre) -> Self {
-        self.genre.replace(genre as u8);
+        self.params.genre.replace(genre as u8);
 
         self
     }
@@ -767,7 +1542,7 @@ impl<'a> GetBeatmapsetSearch<'a> {
     /// Specify a language for the mapsets, defaults to `Any`.
     #[inline]
     pub fn language(mut self, language: Language) -> Self {
-        self.language.replace(language as u8);
+        self.params.language.replace(language as u8);
 
         self
     }
@@ -775,7 +1550,7 @@ impl<'a> GetBeatmapsetSearch<'a> {
     /// Specify whether mapsets can have a video, defaults to `false`.
     #[inline]
     pub fn video(mut self, video: bool) -> Self {
-        self.video = video;
+        self.params.video = video;
 
         self
     }
@@ -783,7 +1558,7 @@ impl<'a> GetBeatmapsetSearch<'a> {
     /// Specify whether mapsets can have a storyboard, defaults to `false`.
     #[inline]
     pub fn storyboard(mut self, storyboard: bool) -> Self {
-        self.storyboard = storyboard;
+        self.params.storyboard = storyboard;
 
         self
     }
@@ -791,7 +1566,74 @@ impl<'a> GetBeatmapsetSearch<'a> {
     /// Specify whether mapsets can be NSFW, defaults to `true`.
     #[inline]
     pub fn nsfw(mut self, nsfw: bool) -> Self {
-        self.nsfw = nsfw;
+        self.params.nsfw = nsfw;
+
+        self
+    }
+
+    /// Specify whether the authenticated user must have played (`true`) or
+    /// must not have played (`false`) the mapsets, defaults to either.
+    ///
+    /// Requires OAuth and osu!supporter of the authenticated user.
+    #[inline]
+    pub fn played(mut self, played: bool) -> Self {
+        self.params.played.replace(played);
+
+        self
+    }
+
+    /// Specify the grades that the authenticated user must have achieved on
+    /// the mapsets, defaults to any.
+    ///
+    /// Requires OAuth and osu!supporter of the authenticated user.
+    #[inline]
+    pub fn rank_achieved(mut self, grades: impl IntoIterator<Item = Grade>) -> Self {
+        self.params.ranks = grades
+            .into_iter()
+            .filter(|&grade| grade != Grade::F)
+            .collect();
+
+        self
+    }
+
+    /// Specify whether mapsets must have a difficulty that is recommended for
+    /// the authenticated user, defaults to `false`.
+    #[inline]
+    pub fn recommended(mut self, recommended: bool) -> Self {
+        self.params.recommended = recommended;
+
+        self
+    }
+
+    /// Specify whether converted maps should be included, defaults to `false`.
+    #[inline]
+    pub fn converts(mut self, converts: bool) -> Self {
+        self.params.converts = converts;
+
+        self
+    }
+
+    /// Specify whether mapsets must be created by mappers that the
+    /// authenticated user follows, defaults to `false`.
+    #[inline]
+    pub fn follows(mut self, follows: bool) -> Self {
+        self.params.follows = follows;
+
+        self
+    }
+
+    /// Specify whether mapsets must be part of a spotlight, defaults to `false`.
+    #[inline]
+    pub fn spotlights(mut self, spotlights: bool) -> Self {
+        self.params.spotlights = spotlights;
+
+        self
+    }
+
+    /// Specify whether mapsets must use a song by a featured artist, defaults to `false`.
+    #[inline]
+    pub fn featured_artists(mut self, featured_artists: bool) -> Self {
+        self.params.featured_artists = featured_artists;
 
         self
     }
@@ -799,15 +1641,23 @@ impl<'a> GetBeatmapsetSearch<'a> {
     /// Specify how the result should be sorted
     #[inline]
     pub fn sort(mut self, sort: BeatmapsetSearchSort, descending: bool) -> Self {
-        self.sort.replace(sort);
-        self.descending = descending;
+        self.params.sort = sort;
+        self.params.descending = descending;
+        self.sort = true;
 
         self
     }
 
+    /// Continue a previous search, see [`BeatmapsetSearchResult::continuation`].
+    ///
+    /// All parameters of the previous search, including its sort, are restored
+    /// and previously specified parameters are overwritten.
     #[inline]
-    pub(crate) fn cursor(mut self, cursor: Cursor) -> Self {
-        self.cursor.replace(cursor);
+    pub fn continuation(mut self, continuation: BeatmapsetSearchContinuation) -> Self {
+        self.params = continuation.params;
+        self.sort = true;
+        self.cursor = continuation.cursor;
+        self.cursor_string = continuation.cursor_string;
 
         self
     }
@@ -816,26 +1666,18 @@ impl<'a> GetBeatmapsetSearch<'a> {
         #[cfg(feature = "metrics")]
         self.osu.metrics.beatmapset_search.inc();
 
-        let q = self.query.take();
-        let mode = self.mode;
-        let status = self.status;
-        let genre = self.genre;
-        let language = self.language;
-        let video = self.video;
-        let storyboard = self.storyboard;
-        let nsfw = self.nsfw;
-
+        let mut params = mem::take(&mut self.params);
         let mut query = Query::new();
 
-        if let Some(ref q) = q {
+        if let Some(ref q) = params.query {
             query.push("q", q);
         }
 
-        if let Some(mode) = mode {
+        if let Some(mode) = params.mode {
             query.push("m", mode);
         }
 
-        match status {
+        match params.status {
             None => {}
             Some(SearchRankStatus::Specific(status)) => {
                 let mut buf = String::new();
@@ -852,15 +1694,15 @@ impl<'a> GetBeatmapsetSearch<'a> {
             }
         }
 
-        if let Some(genre) = genre {
+        if let Some(genre) = params.genre {
             query.push("g", genre);
         }
 
-        if let Some(language) = language {
+        if let Some(language) = params.language {
             query.push("l", language);
         }
 
-        let extra = match (video, storyboard) {
+        let extra = match (params.video, params.storyboard) {
             (false, false) => None,
             (false, true) => Some("storyboard"),
             (true, false) => Some("video"),
@@ -871,39 +1713,73 @@ impl<'a> GetBeatmapsetSearch<'a> {
             query.push("e", extra);
         }
 
-        query.push("nsfw", nsfw);
+        query.push("nsfw", params.nsfw);
 
-        if let Some(cursor) = self.cursor.take() {
+        if let Some(played) = params.played {
+            query.push("played", if played { "played" } else { "unplayed" });
+        }
+
+        if !params.ranks.is_empty() {
+            let mut buf = String::new();
+
+            for grade in params.ranks.iter() {
+                if !buf.is_empty() {
+                    buf.push('.');
+                }
+
+                let _ = write!(buf, "{}", grade);
+            }
+
+            query.push("r", buf);
+        }
+
+        let general = [
+            (params.recommended, "recommended"),
+            (params.converts, "converts"),
+            (params.follows, "follows"),
+            (params.spotlights, "spotlights"),
+            (params.featured_artists, "featured_artists"),
+        ];
+
+        let general: Vec<_> = general
+            .iter()
+            .filter(|(enabled, _)| *enabled)
+            .map(|(_, name)| *name)
+            .collect();
+
+        if !general.is_empty() {
+            query.push("c", general.join("."));
+        }
+
+        if let Some(cursor_string) = self.cursor_string.take() {
+            query.push("cursor_string", cursor_string);
+        } else if let Some(cursor) = self.cursor.take() {
             cursor.push_to_query(&mut query);
         }
 
-        if let Some(ref sort) = self.sort {
+        if self.sort {
             let mut buf = String::with_capacity(16);
-            let _ = write!(buf, "{}_", sort);
-            let order = if self.descending { "desc" } else { "asc" };
+            let _ = write!(buf, "{}_", params.sort);
+            let order = if params.descending { "desc" } else { "asc" };
             buf.push_str(order);
 
             query.push("sort", buf);
         }
 
         let req = Request::with_query(Route::GetBeatmapsetSearch, query);
-        let osu = self.osu;
 
-        let fut = osu
-            .request::<BeatmapsetSearchResult>(req)
-            .map_ok(move |mut search_result| {
-                let params = &mut search_result.params;
-                params.query = q;
-                params.mode = mode;
-                params.status = status;
-                params.genre = genre;
-                params.language = language;
-                params.video = video;
-                params.storyboard = storyboard;
-                params.nsfw = nsfw;
-
-                search_result
-            });
+        let fut =
+            self.osu
+                .request::<BeatmapsetSearchResult>(req)
+                .map_ok(move |mut search_result| {
+                    // The response only contains the sort so the
+                    // remaining parameters are taken from the request
+                    params.sort = search_result.params.sort;
+                    params.descending = search_result.params.descending;
+                    search_result.params = params;
+
+                    search_result
+                });
 
         Box::pin(fut)
     }
@@ -955,3 +1831,125 @@ impl<'a> GetScore<'a> {
 }
 
 poll_req!(GetScore => Score);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::model::beatmap::SearchQuery;
+    use time::{Date, Month};
+
+    #[test]
+    fn star_mods_per_mode() {
+        let hd = GameMods::Hidden;
+        let fl = GameMods::Flashlight;
+        let hr = GameMods::HardRock;
+        let nc = GameMods::NightCore;
+
+        assert_eq!(star_mods(hd | hr, Some(GameMode::Osu)), hr);
+        assert_eq!(star_mods(hd | fl, Some(GameMode::Osu)), hd | fl);
+        assert_eq!(star_mods(hd | fl, None), hd | fl);
+        assert_eq!(
+            star_mods(GameMods::TouchDevice, None),
+            GameMods::TouchDevice
+        );
+        assert_eq!(star_mods(hd | fl | hr, Some(GameMode::Taiko)), hr);
+        assert_eq!(
+            star_mods(fl | GameMods::Easy, Some(GameMode::Catch)),
+            GameMods::Easy
+        );
+        assert_eq!(star_mods(nc, Some(GameMode::Catch)), GameMods::DoubleTime);
+        assert_eq!(
+            star_mods(GameMods::Key4 | hd, Some(GameMode::Mania)),
+            GameMods::Key4
+        );
+        assert_eq!(star_mods(GameMods::Key4, None), GameMods::NoMod);
+    }
+
+    #[test]
+    fn star_mods_dedupe_and_expand() {
+        let mods = [
+            GameMods::NoMod,
+            GameMods::Hidden,
+            GameMods::DoubleTime,
+            GameMods::NightCore | GameMods::Hidden,
+            GameMods::Flashlight,
+            GameMods::Flashlight | GameMods::Hidden,
+        ];
+
+        let keys = star_mods_keys(&mods, Some(GameMode::Osu));
+
+        let expected = [
+            GameMods::NoMod,
+            GameMods::Hidden | GameMods::Flashlight,
+            GameMods::DoubleTime,
+            GameMods::Flashlight,
+        ];
+
+        assert_eq!(keys.len(), expected.len());
+        assert!(expected.iter().all(|key| keys.contains(key)));
+
+        let map_ids = [1, 2];
+
+        let attrs: HashMap<_, _> = map_ids
+            .iter()
+            .flat_map(|&map_id| keys.iter().map(move |&key| ((map_id, key), (map_id, key))))
+            .collect();
+
+        let expanded = expand_star_mods(&map_ids, &mods, Some(GameMode::Osu), &attrs);
+
+        assert_eq!(expanded.len(), 2);
+
+        for &map_id in map_ids.iter() {
+            let map_attrs = &expanded[&map_id];
+            assert_eq!(map_attrs.len(), mods.len());
+
+            for &m in mods.iter() {
+                assert_eq!(map_attrs[&m], (map_id, star_mods(m, Some(GameMode::Osu))));
+            }
+        }
+
+        assert_eq!(expanded[&1][&GameMods::Hidden].1, GameMods::NoMod);
+        assert_eq!(
+            expanded[&1][&(GameMods::NightCore | GameMods::Hidden)].1,
+            GameMods::DoubleTime
+        );
+    }
+
+    #[test]
+    fn search_query_is_percent_encoded() {
+        let q = SearchQuery::new()
+            .text("rock&roll")
+            .text("c++")
+            .title(r#"a "b""#);
+
+        let mut query = Query::new();
+        query.push("q", q);
+
+        assert_eq!(
+            query.to_string(),
+            "?q=rock%26roll+c%2B%2B+title%3D%22a+%5C%22b%5C%22%22"
+        );
+    }
+
+    #[test]
+    fn cursor_string_is_percent_encoded() {
+        let mut query = Query::new();
+        query.push("cursor_string", "eyJhIjoxfQ+/z==");
+
+        assert_eq!(query.to_string(), "?cursor_string=eyJhIjoxfQ%2B%2Fz%3D%3D");
+    }
+
+    #[test]
+    fn format_date_clamps_year() {
+        let date = |year| {
+            Date::from_calendar_date(year, Month::March, 1)
+                .unwrap()
+                .midnight()
+                .assume_utc()
+        };
+
+        assert_eq!(format_date(date(2024)), "2024-03-01T00:00:00Z");
+        assert_eq!(format_date(date(-5)), "0000-01-01T00:00:00Z");
+    }
+}
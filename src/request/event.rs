@@ -0,0 +1,78 @@
+use crate::{
+    model::{
+        recent_event_::{EventSort, Events},
+        Cursor,
+    },
+    request::{Pending, Query, Request},
+    routing::Route,
+    Osu,
+};
+
+use futures::future::TryFutureExt;
+
+/// Get [`Events`](crate::model::recent_event::Events) i.e. recent
+/// site-wide events like achieved medals, ranks on beatmaps,
+/// or beatmapset status updates.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct GetEvents<'a> {
+    fut: Option<Pending<'a, Events>>,
+    osu: &'a Osu,
+    sort: Option<EventSort>,
+    cursor: Option<Cursor>,
+}
+
+impl<'a> GetEvents<'a> {
+    #[inline]
+    pub(crate) fn new(osu: &'a Osu) -> Self {
+        Self {
+            fut: None,
+            osu,
+            sort: None,
+            cursor: None,
+        }
+    }
+
+    /// Specify the order of the events
+    #[inline]
+    pub fn sort(mut self, sort: EventSort) -> Self {
+        self.sort.replace(sort);
+
+        self
+    }
+
+    #[inline]
+    #[cfg(not(feature = "rkyv"))]
+    pub(crate) fn cursor(mut self, cursor: Cursor) -> Self {
+        self.cursor.replace(cursor);
+
+        self
+    }
+
+    fn start(&mut self) -> Pending<'a, Events> {
+        #[cfg(feature = "metrics")]
+        self.osu.metrics.events.inc();
+
+        let mut query = Query::new();
+
+        if let Some(sort) = self.sort {
+            query.push("sort", sort);
+        }
+
+        if let Some(cursor) = self.cursor.take() {
+            cursor.push_to_query(&mut query);
+        }
+
+        let req = Request::with_query(Route::GetEvents, query);
+        let sort = self.sort;
+
+        let fut = self.osu.request::<Events>(req).map_ok(move |mut events| {
+            events.sort = sort;
+
+            events
+        });
+
+        Box::pin(fut)
+    }
+}
+
+poll_req!(GetEvents => Events);
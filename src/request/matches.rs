@@ -1,14 +1,13 @@
 use crate::{
     model::{
-        matches_::{MatchList, OsuMatch},
+        matches_::{MatchEvent, MatchList, OsuMatch},
         Cursor,
     },
-    request::{Pending, Query, Request},
+    request::{JsonArrayStream, Pending, Query, Request, ResponseStream},
     routing::Route,
     Osu,
 };
 
-#[cfg(feature = "cache")]
 use futures::TryFutureExt;
 
 /// Get an [`OsuMatch`](crate::model::matches::OsuMatch) by its id
@@ -102,6 +101,90 @@ impl<'a> GetMatch<'a> {
 
 poll_req!(GetMatch => OsuMatch);
 
+/// Get the [`MatchEvent`]s of a multiplayer match, deserialized one by one
+/// while the response is received.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct GetMatchEvents<'a> {
+    fut: Option<Pending<'a, JsonArrayStream<MatchEvent>>>,
+    osu: &'a Osu,
+    match_id: u32,
+    after: Option<u64>,
+    before: Option<u64>,
+    limit: Option<usize>,
+}
+
+impl<'a> GetMatchEvents<'a> {
+    #[inline]
+    pub(crate) fn new(osu: &'a Osu, match_id: u32) -> Self {
+        Self {
+            fut: None,
+            osu,
+            match_id,
+            after: None,
+            before: None,
+            limit: None,
+        }
+    }
+
+    /// Only include events after the given event id
+    #[inline]
+    pub fn after(mut self, after: u64) -> Self {
+        self.after.replace(after);
+
+        self
+    }
+
+    /// Only include events before the given event id
+    #[inline]
+    pub fn before(mut self, before: u64) -> Self {
+        self.before.replace(before);
+
+        self
+    }
+
+    /// Limit the amount of events
+    #[inline]
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit.replace(limit);
+
+        self
+    }
+
+    fn start(&mut self) -> Pending<'a, JsonArrayStream<MatchEvent>> {
+        #[cfg(feature = "metrics")]
+        self.osu.metrics.osu_match.inc();
+
+        let mut query = Query::new();
+
+        if let Some(after) = self.after {
+            query.push("after", after);
+        }
+
+        if let Some(before) = self.before {
+            query.push("before", before);
+        }
+
+        if let Some(limit) = self.limit {
+            query.push("limit", limit);
+        }
+
+        let route = Route::GetMatch {
+            match_id: Some(self.match_id),
+        };
+
+        let req = Request::with_query(route, query);
+
+        let fut = self
+            .osu
+            .request_stream(req)
+            .map_ok(|body| JsonArrayStream::new(ResponseStream::new(body), Some("events")));
+
+        Box::pin(fut)
+    }
+}
+
+poll_req!(GetMatchEvents => JsonArrayStream<MatchEvent>);
+
 /// Get a [`MatchList`](crate::model::matches::MatchList) containing all
 /// currently open multiplayer lobbies.
 #[must_use = "futures do nothing unless you `.await` or poll them"]
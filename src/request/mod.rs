@@ -22,23 +22,33 @@ macro_rules! poll_req {
 
 mod beatmap;
 mod comments;
+mod event;
 mod forum;
 mod matches;
 mod news;
 mod ranking;
 mod replay;
+mod room;
+mod search;
 mod seasonal_backgrounds;
+mod stream;
+mod team;
 mod user;
 mod wiki;
 
 pub use beatmap::*;
 pub use comments::*;
+pub use event::*;
 pub use forum::*;
 pub use matches::*;
 pub use news::*;
 pub use ranking::*;
 pub use replay::*;
+pub use room::*;
+pub use search::*;
 pub use seasonal_backgrounds::*;
+pub use stream::{JsonArrayStream, ResponseStream};
+pub use team::*;
 pub use user::*;
 pub use wiki::*;
 
@@ -51,6 +61,7 @@ use std::{
     future::Future,
     pin::Pin,
 };
+use url::form_urlencoded;
 
 type Pending<'a, T> = Pin<Box<dyn Future<Output = OsuResult<T>> + Send + Sync + 'a>>;
 
@@ -137,10 +148,13 @@ impl Query {
         Self::default()
     }
 
+    /// Append the key and the percent-encoded value
     pub(crate) fn push(&mut self, key: &str, value: impl Display) {
         self.query.push_str(key);
         self.query.push('=');
-        let _ = write!(self.query, "{}", value);
+        let value = value.to_string();
+        self.query
+            .extend(form_urlencoded::byte_serialize(value.as_bytes()));
         self.query.push('&');
     }
 }
@@ -156,3 +170,17 @@ impl Display for Query {
         f.write_str(&self.query[..self.query.len() - 1])
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn query_encodes_values() {
+        let mut query = Query::new();
+        query.push("query", "a&b c++");
+        query.push("mode", "all");
+
+        assert_eq!(query.to_string(), "?query=a%26b+c%2B%2B&mode=all");
+    }
+}
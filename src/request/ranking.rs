@@ -1,16 +1,175 @@
 use crate::{
     model::{
-        ranking_::{ChartRankings, CountryRankings, RankingType, Rankings, Spotlight},
-        user_::CountryCode,
+        ranking_::{
+            ChartRankings, CountryRankings, KudosuRankings, RankingFilter, RankingType,
+            RankingVariant, Rankings, Spotlight, TeamRankings,
+        },
+        user_::{CountryCode, UserCompact},
         GameMode,
     },
     request::{Pending, Query, Request},
     routing::Route,
-    Osu,
+    Osu, OsuResult,
 };
 
-use futures::future::TryFutureExt;
+use futures::{
+    future::TryFutureExt,
+    stream::{self, Stream},
+};
 use serde::Deserialize;
+use std::future::Future;
+
+/// The last page of a ranking that the API provides, i.e. 10,000 entries.
+const MAX_PAGE: u32 = 200;
+
+/// The last page of the kudosu ranking that the API provides, i.e. 1,000 users.
+const KUDOSU_MAX_PAGE: u32 = 20;
+
+/// The amount of users per page of the kudosu ranking.
+const KUDOSU_PAGE_SIZE: usize = 50;
+
+/// Filters of a ranking request.
+///
+/// Only the filters applicable to the requested [`RankingType`] are sent.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) struct RankingParams {
+    pub(crate) country: Option<CountryCode>,
+    pub(crate) filter: Option<RankingFilter>,
+    pub(crate) page: Option<u32>,
+    pub(crate) spotlight: Option<u32>,
+    pub(crate) team: Option<u32>,
+    pub(crate) variant: Option<RankingVariant>,
+}
+
+impl RankingParams {
+    fn push_to_query(&self, mode: GameMode, kind: RankingType, query: &mut Query) {
+        let users = matches!(kind, RankingType::Performance | RankingType::Score);
+
+        if let Some(ref country) = self.country.as_ref().filter(|_| users) {
+            query.push("country", country);
+        }
+
+        if let Some(team_id) = self.team.filter(|_| users) {
+            query.push("team", team_id);
+        }
+
+        let teams = matches!(kind, RankingType::Country | RankingType::Team);
+
+        if let Some(filter) = self.filter.filter(|_| !teams) {
+            query.push("filter", filter);
+        }
+
+        // ! Adjust filter once there are non-mania variants
+        if let Some(variant) = self.variant.filter(|_| users && mode == GameMode::Mania) {
+            query.push("variant", variant);
+        }
+
+        if let Some(spotlight) = self.spotlight.filter(|_| kind == RankingType::Charts) {
+            query.push("spotlight", spotlight);
+        }
+
+        if let Some(page) = self.page.filter(|_| kind != RankingType::Charts) {
+            query.push("cursor[page]", page);
+        }
+    }
+}
+
+trait RankingPage {
+    fn next_page(&self) -> Option<u32>;
+}
+
+impl RankingPage for CountryRankings {
+    #[inline]
+    fn next_page(&self) -> Option<u32> {
+        self.next_page
+    }
+}
+
+impl RankingPage for KudosuRankings {
+    #[inline]
+    fn next_page(&self) -> Option<u32> {
+        self.next_page
+    }
+}
+
+impl RankingPage for Rankings {
+    #[inline]
+    fn next_page(&self) -> Option<u32> {
+        self.next_page
+    }
+}
+
+impl RankingPage for TeamRankings {
+    #[inline]
+    fn next_page(&self) -> Option<u32> {
+        self.next_page
+    }
+}
+
+/// Request the given page and all following ones up to [`MAX_PAGE`].
+fn ranking_stream<'a, T, F, R>(
+    page: Option<u32>,
+    mut request: F,
+) -> impl Stream<Item = OsuResult<T>> + 'a
+where
+    T: RankingPage + 'a,
+    F: FnMut(u32) -> R + 'a,
+    R: Future<Output = OsuResult<T>> + 'a,
+{
+    stream::try_unfold(Some(page.unwrap_or(1)), move |page| {
+        let fut = page.map(&mut request);
+
+        async move {
+            let fut = match fut {
+                Some(fut) => fut,
+                None => return Ok(None),
+            };
+
+            let ranking = fut.await?;
+            let next_page = ranking.next_page().filter(|&page| page <= MAX_PAGE);
+
+            Ok(Some((ranking, next_page)))
+        }
+    })
+}
+
+fn request_rankings<'a>(
+    osu: &'a Osu,
+    mode: GameMode,
+    kind: RankingType,
+    params: &RankingParams,
+) -> impl Future<Output = OsuResult<Rankings>> + 'a {
+    let mut query = Query::new();
+    params.push_to_query(mode, kind, &mut query);
+
+    let route = Route::GetRankings {
+        mode,
+        ranking_type: kind,
+    };
+
+    let req = Request::with_query(route, query);
+
+    #[cfg(not(feature = "rkyv"))]
+    let params = params.clone();
+
+    osu.request::<Rankings>(req)
+        .map_ok(move |mut rankings: Rankings| {
+            rankings.mode.replace(mode);
+
+            #[cfg(not(feature = "rkyv"))]
+            {
+                rankings.ranking_type.replace(kind);
+                rankings.params = params;
+            }
+
+            #[cfg(feature = "cache")]
+            for user in rankings.ranking.iter() {
+                osu.update_cache(user.user_id, &user.username);
+            }
+
+            rankings
+        })
+}
 
 /// Get a [`ChartRankings`](crate::model::ranking::ChartRankings) struct
 /// containing a [`Spotlight`](crate::model::ranking::Spotlight), its
@@ -28,7 +187,7 @@ pub struct GetChartRankings<'a> {
     fut: Option<Pending<'a, ChartRankings>>,
     osu: &'a Osu,
     mode: GameMode,
-    spotlight: Option<u32>,
+    params: RankingParams,
 }
 
 impl<'a> GetChartRankings<'a> {
@@ -38,7 +197,7 @@ impl<'a> GetChartRankings<'a> {
             fut: None,
             osu,
             mode,
-            spotlight: None,
+            params: RankingParams::default(),
         }
     }
 
@@ -46,7 +205,16 @@ impl<'a> GetChartRankings<'a> {
     /// the latest spotlight will be returned.
     #[inline]
     pub fn spotlight(mut self, spotlight_id: u32) -> Self {
-        self.spotlight.replace(spotlight_id);
+        self.params.spotlight.replace(spotlight_id);
+
+        self
+    }
+
+    /// Only include friends of the authenticated user
+    /// if [`RankingFilter::Friends`] is given.
+    #[inline]
+    pub fn filter(mut self, filter: RankingFilter) -> Self {
+        self.params.filter.replace(filter);
 
         self
     }
@@ -56,10 +224,8 @@ impl<'a> GetChartRankings<'a> {
         self.osu.metrics.chart_rankings.inc();
 
         let mut query = Query::new();
-
-        if let Some(spotlight) = self.spotlight {
-            query.push("spotlight", &spotlight);
-        }
+        self.params
+            .push_to_query(self.mode, RankingType::Charts, &mut query);
 
         let route = Route::GetRankings {
             mode: self.mode,
@@ -86,12 +252,15 @@ poll_req!(GetChartRankings => ChartRankings);
 /// Get a [`CountryRankings`](crate::model::ranking::CountryRankings) struct
 /// containing a vec of [`CountryRanking`](crate::model::ranking::CountryRanking)s
 /// which will be sorted by the country's total pp.
+///
+/// To walk through all pages, use
+/// [`into_stream`](crate::request::GetCountryRankings::into_stream).
 #[must_use = "futures do nothing unless you `.await` or poll them"]
 pub struct GetCountryRankings<'a> {
     fut: Option<Pending<'a, CountryRankings>>,
     osu: &'a Osu,
     mode: GameMode,
-    page: Option<u32>,
+    params: RankingParams,
 }
 
 impl<'a> GetCountryRankings<'a> {
@@ -101,27 +270,35 @@ impl<'a> GetCountryRankings<'a> {
             fut: None,
             osu,
             mode,
-            page: None,
+            params: RankingParams::default(),
         }
     }
 
     /// Specify a page
     #[inline]
     pub fn page(mut self, page: u32) -> Self {
-        self.page.replace(page);
+        self.params.page.replace(page);
 
         self
     }
 
+    /// Turn the request into a stream that yields one page after the other,
+    /// starting at the specified page or the first one.
+    pub fn into_stream(self) -> impl Stream<Item = OsuResult<CountryRankings>> + 'a {
+        let Self {
+            osu, mode, params, ..
+        } = self;
+
+        ranking_stream(params.page, move |page| Self::new(osu, mode).page(page))
+    }
+
     fn start(&mut self) -> Pending<'a, CountryRankings> {
         #[cfg(feature = "metrics")]
         self.osu.metrics.country_rankings.inc();
 
         let mut query = Query::new();
-
-        if let Some(page) = self.page {
-            query.push("cursor[page]", &page);
-        }
+        self.params
+            .push_to_query(self.mode, RankingType::Country, &mut query);
 
         let route = Route::GetRankings {
             mode: self.mode,
@@ -136,17 +313,109 @@ impl<'a> GetCountryRankings<'a> {
 
 poll_req!(GetCountryRankings => CountryRankings);
 
+/// Get a [`KudosuRankings`](crate::model::ranking::KudosuRankings) struct whose
+/// [`UserCompact`](crate::model::user::UserCompact)s are sorted
+/// by their total kudosu, i.e. the current kudosu leaderboard.
+///
+/// The users will have the `kudosu` option filled.
+///
+/// To walk through all pages, use
+/// [`into_stream`](crate::request::GetKudosuRankings::into_stream).
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct GetKudosuRankings<'a> {
+    fut: Option<Pending<'a, KudosuRankings>>,
+    osu: &'a Osu,
+    page: Option<u32>,
+}
+
+impl<'a> GetKudosuRankings<'a> {
+    #[inline]
+    pub(crate) fn new(osu: &'a Osu) -> Self {
+        Self {
+            fut: None,
+            osu,
+            page: None,
+        }
+    }
+
+    /// Pages range from 1 to 20, each containing 50 users.
+    #[inline]
+    pub fn page(mut self, page: u32) -> Self {
+        self.page.replace(page);
+
+        self
+    }
+
+    /// Turn the request into a stream that yields one page after the other,
+    /// starting at the specified page or the first one.
+    pub fn into_stream(self) -> impl Stream<Item = OsuResult<KudosuRankings>> + 'a {
+        let Self { osu, page, .. } = self;
+
+        ranking_stream(page, move |page| Self::new(osu).page(page))
+    }
+
+    fn start(&mut self) -> Pending<'a, KudosuRankings> {
+        #[cfg(feature = "metrics")]
+        self.osu.metrics.kudosu_rankings.inc();
+
+        let mut query = Query::new();
+
+        if let Some(page) = self.page {
+            query.push("page", page);
+        }
+
+        let req = Request::with_query(Route::GetKudosuRankings, query);
+        let osu = self.osu;
+        let page = self.page.unwrap_or(1);
+
+        let fut = osu
+            .request::<KudosuRankingsResponse>(req)
+            .map_ok(move |response| {
+                // The API provides no cursor so the next page is only assumed
+                // to exist if the current one is full
+                let next_page =
+                    if page < KUDOSU_MAX_PAGE && response.ranking.len() == KUDOSU_PAGE_SIZE {
+                        Some(page + 1)
+                    } else {
+                        None
+                    };
+
+                KudosuRankings {
+                    next_page,
+                    ranking: response.ranking,
+                }
+            });
+
+        #[cfg(feature = "cache")]
+        let fut = fut.inspect_ok(move |rankings| {
+            for user in rankings.ranking.iter() {
+                osu.update_cache(user.user_id, &user.username);
+            }
+        });
+
+        Box::pin(fut)
+    }
+}
+
+poll_req!(GetKudosuRankings => KudosuRankings);
+
+#[derive(Deserialize)]
+struct KudosuRankingsResponse {
+    ranking: Vec<UserCompact>,
+}
+
 /// Get a [`Rankings`](crate::model::ranking::Rankings) struct whose
 /// [`UserCompact`](crate::model::user::UserCompact)s are sorted
 /// by their pp, i.e. the current pp leaderboard.
+///
+/// To walk through all pages, use
+/// [`into_stream`](crate::request::GetPerformanceRankings::into_stream).
 #[must_use = "futures do nothing unless you `.await` or poll them"]
 pub struct GetPerformanceRankings<'a> {
     fut: Option<Pending<'a, Rankings>>,
     osu: &'a Osu,
     mode: GameMode,
-    country: Option<CountryCode>,
-    variant: Option<&'static str>,
-    page: Option<u32>,
+    params: RankingParams,
 }
 
 impl<'a> GetPerformanceRankings<'a> {
@@ -156,89 +425,94 @@ impl<'a> GetPerformanceRankings<'a> {
             fut: None,
             osu,
             mode,
-            country: None,
-            variant: None,
-            page: None,
+            params: RankingParams::default(),
         }
     }
 
     /// Specify a country code.
     #[inline]
     pub fn country(mut self, country: impl Into<CountryCode>) -> Self {
-        self.country.replace(country.into());
+        self.params.country.replace(country.into());
 
         self
     }
 
-    /// Consider only 4K scores. Only relevant for osu!mania.
+    /// Only include friends of the authenticated user
+    /// if [`RankingFilter::Friends`] is given.
     #[inline]
-    pub fn variant_4k(mut self) -> Self {
-        self.variant.replace("4k");
+    pub fn filter(mut self, filter: RankingFilter) -> Self {
+        self.params.filter.replace(filter);
 
         self
     }
 
-    /// Consider only 7K scores. Only relevant for osu!mania.
+    /// Only include members of the given team.
     #[inline]
-    pub fn variant_7k(mut self) -> Self {
-        self.variant.replace("7k");
+    pub fn team(mut self, team_id: u32) -> Self {
+        self.params.team.replace(team_id);
 
         self
     }
 
-    /// Pages range from 1 to 200.
+    /// Consider only scores of the given variant. Only relevant for osu!mania.
     #[inline]
-    pub fn page(mut self, page: u32) -> Self {
-        self.page.replace(page);
+    pub fn variant(mut self, variant: RankingVariant) -> Self {
+        self.params.variant.replace(variant);
 
         self
     }
 
-    fn start(&mut self) -> Pending<'a, Rankings> {
-        #[cfg(feature = "metrics")]
-        self.osu.metrics.performance_rankings.inc();
-
-        let mode = self.mode;
-        let mut query = Query::new();
-
-        if let Some(ref country) = self.country {
-            query.push("country", country);
-        }
+    /// Consider only 4K scores. Only relevant for osu!mania.
+    #[inline]
+    pub fn variant_4k(self) -> Self {
+        self.variant(RankingVariant::FourKey)
+    }
 
-        // ! Adjust filter once there are non-mania variants
-        if let Some(variant) = self.variant.filter(|_| mode == GameMode::Mania) {
-            query.push("variant", &variant);
-        }
+    /// Consider only 7K scores. Only relevant for osu!mania.
+    #[inline]
+    pub fn variant_7k(self) -> Self {
+        self.variant(RankingVariant::SevenKey)
+    }
 
-        if let Some(page) = self.page {
-            query.push("cursor[page]", &page);
-        }
+    /// Pages range from 1 to 200.
+    #[inline]
+    pub fn page(mut self, page: u32) -> Self {
+        self.params.page.replace(page);
 
-        let route = Route::GetRankings {
-            mode,
-            ranking_type: RankingType::Performance,
-        };
+        self
+    }
 
-        let req = Request::with_query(route, query);
-        let osu = self.osu;
+    #[inline]
+    pub(crate) fn params(mut self, params: RankingParams) -> Self {
+        self.params = params;
 
-        let fut = osu
-            .request::<Rankings>(req)
-            .map_ok(move |mut rankings: Rankings| {
-                rankings.mode.replace(mode);
+        self
+    }
 
-                #[cfg(not(feature = "rkyv"))]
-                rankings.ranking_type.replace(RankingType::Performance);
+    /// Turn the request into a stream that yields one page after the other,
+    /// starting at the specified page or the first one.
+    ///
+    /// The stream ends after page 200, i.e. after 10,000 users.
+    pub fn into_stream(self) -> impl Stream<Item = OsuResult<Rankings>> + 'a {
+        let Self {
+            osu, mode, params, ..
+        } = self;
+
+        ranking_stream(params.page, move |page| {
+            let mut params = params.clone();
+            params.page = Some(page);
+
+            Self::new(osu, mode).params(params)
+        })
+    }
 
-                #[cfg(feature = "cache")]
-                for user in rankings.ranking.iter() {
-                    osu.update_cache(user.user_id, &user.username);
-                }
+    fn start(&mut self) -> Pending<'a, Rankings> {
+        #[cfg(feature = "metrics")]
+        self.osu.metrics.performance_rankings.inc();
 
-                rankings
-            });
+        let kind = RankingType::Performance;
 
-        Box::pin(fut)
+        Box::pin(request_rankings(self.osu, self.mode, kind, &self.params))
     }
 }
 
@@ -247,12 +521,15 @@ poll_req!(GetPerformanceRankings => Rankings);
 /// Get a [`Rankings`](crate::model::ranking::Rankings) struct whose
 /// [`UserCompact`](crate::model::user::UserCompact)s are sorted
 /// by their ranked score, i.e. the current ranked score leaderboard.
+///
+/// To walk through all pages, use
+/// [`into_stream`](crate::request::GetScoreRankings::into_stream).
 #[must_use = "futures do nothing unless you `.await` or poll them"]
 pub struct GetScoreRankings<'a> {
     fut: Option<Pending<'a, Rankings>>,
     osu: &'a Osu,
     mode: GameMode,
-    page: Option<u32>,
+    params: RankingParams,
 }
 
 impl<'a> GetScoreRankings<'a> {
@@ -262,58 +539,150 @@ impl<'a> GetScoreRankings<'a> {
             fut: None,
             osu,
             mode,
-            page: None,
+            params: RankingParams::default(),
         }
     }
 
+    /// Specify a country code.
+    #[inline]
+    pub fn country(mut self, country: impl Into<CountryCode>) -> Self {
+        self.params.country.replace(country.into());
+
+        self
+    }
+
+    /// Only include friends of the authenticated user
+    /// if [`RankingFilter::Friends`] is given.
+    #[inline]
+    pub fn filter(mut self, filter: RankingFilter) -> Self {
+        self.params.filter.replace(filter);
+
+        self
+    }
+
+    /// Only include members of the given team.
+    #[inline]
+    pub fn team(mut self, team_id: u32) -> Self {
+        self.params.team.replace(team_id);
+
+        self
+    }
+
+    /// Consider only scores of the given variant. Only relevant for osu!mania.
+    #[inline]
+    pub fn variant(mut self, variant: RankingVariant) -> Self {
+        self.params.variant.replace(variant);
+
+        self
+    }
+
     /// Pages range from 1 to 200.
     #[inline]
     pub fn page(mut self, page: u32) -> Self {
-        self.page.replace(page);
+        self.params.page.replace(page);
+
+        self
+    }
+
+    #[inline]
+    pub(crate) fn params(mut self, params: RankingParams) -> Self {
+        self.params = params;
 
         self
     }
 
+    /// Turn the request into a stream that yields one page after the other,
+    /// starting at the specified page or the first one.
+    ///
+    /// The stream ends after page 200, i.e. after 10,000 users.
+    pub fn into_stream(self) -> impl Stream<Item = OsuResult<Rankings>> + 'a {
+        let Self {
+            osu, mode, params, ..
+        } = self;
+
+        ranking_stream(params.page, move |page| {
+            let mut params = params.clone();
+            params.page = Some(page);
+
+            Self::new(osu, mode).params(params)
+        })
+    }
+
     fn start(&mut self) -> Pending<'a, Rankings> {
         #[cfg(feature = "metrics")]
         self.osu.metrics.score_rankings.inc();
 
-        let mode = self.mode;
-        let mut query = Query::new();
+        let kind = RankingType::Score;
 
-        if let Some(page) = self.page {
-            query.push("cursor[page]", &page);
-        }
+        Box::pin(request_rankings(self.osu, self.mode, kind, &self.params))
+    }
+}
 
-        let route = Route::GetRankings {
+poll_req!(GetScoreRankings => Rankings);
+
+/// Get a [`TeamRankings`](crate::model::ranking::TeamRankings) struct
+/// containing a vec of [`TeamRanking`](crate::model::ranking::TeamRanking)s
+/// which will be sorted by the team's total pp.
+///
+/// To walk through all pages, use
+/// [`into_stream`](crate::request::GetTeamRankings::into_stream).
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct GetTeamRankings<'a> {
+    fut: Option<Pending<'a, TeamRankings>>,
+    osu: &'a Osu,
+    mode: GameMode,
+    params: RankingParams,
+}
+
+impl<'a> GetTeamRankings<'a> {
+    #[inline]
+    pub(crate) fn new(osu: &'a Osu, mode: GameMode) -> Self {
+        Self {
+            fut: None,
+            osu,
             mode,
-            ranking_type: RankingType::Score,
-        };
+            params: RankingParams::default(),
+        }
+    }
 
-        let req = Request::with_query(route, query);
-        let osu = self.osu;
+    /// Specify a page
+    #[inline]
+    pub fn page(mut self, page: u32) -> Self {
+        self.params.page.replace(page);
 
-        let fut = osu
-            .request::<Rankings>(req)
-            .map_ok(move |mut rankings: Rankings| {
-                rankings.mode.replace(mode);
+        self
+    }
 
-                #[cfg(not(feature = "rkyv"))]
-                rankings.ranking_type.replace(RankingType::Score);
+    /// Turn the request into a stream that yields one page after the other,
+    /// starting at the specified page or the first one.
+    pub fn into_stream(self) -> impl Stream<Item = OsuResult<TeamRankings>> + 'a {
+        let Self {
+            osu, mode, params, ..
+        } = self;
 
-                #[cfg(feature = "cache")]
-                for user in rankings.ranking.iter() {
-                    osu.update_cache(user.user_id, &user.username);
-                }
+        ranking_stream(params.page, move |page| Self::new(osu, mode).page(page))
+    }
 
-                rankings
-            });
+    fn start(&mut self) -> Pending<'a, TeamRankings> {
+        #[cfg(feature = "metrics")]
+        self.osu.metrics.team_rankings.inc();
 
-        Box::pin(fut)
+        let mut query = Query::new();
+        self.params
+            .push_to_query(self.mode, RankingType::Team, &mut query);
+
+        let route = Route::GetRankings {
+            mode: self.mode,
+            ranking_type: RankingType::Team,
+        };
+
+        let req = Request::with_query(route, query);
+
+        Box::pin(self.osu.request(req))
     }
 }
 
-poll_req!(GetScoreRankings => Rankings);
+poll_req!(GetTeamRankings => TeamRankings);
 
 /// Get a vec of [`Spotlight`](crate::model::ranking::Spotlight)s.
 #[must_use = "futures do nothing unless you `.await` or poll them"]
@@ -4,11 +4,11 @@ use futures::TryFutureExt;
 use futures::FutureExt;
 
 #[cfg(feature = "replay")]
-use osu_db::Replay;
+use crate::model::replay::Replay;
 
 use crate::{
     prelude::GameMode,
-    request::{Pending, Request},
+    request::{Pending, Request, ResponseStream},
     routing::Route,
     Osu,
 };
@@ -50,7 +50,47 @@ impl<'a> GetReplayRaw<'a> {
 
 poll_req!(GetReplayRaw => Vec<u8>);
 
-/// Get a [`Replay`](osu_db::Replay)
+/// Get a raw replay in form of a [`ResponseStream`] that yields the bytes in chunks
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct GetReplayStream<'a> {
+    fut: Option<Pending<'a, ResponseStream>>,
+    osu: &'a Osu,
+    mode: GameMode,
+    score_id: u64,
+}
+
+impl<'a> GetReplayStream<'a> {
+    #[inline]
+    pub(crate) fn new(osu: &'a Osu, mode: GameMode, score_id: u64) -> Self {
+        Self {
+            fut: None,
+            osu,
+            mode,
+            score_id,
+        }
+    }
+
+    fn start(&mut self) -> Pending<'a, ResponseStream> {
+        #[cfg(feature = "metrics")]
+        self.osu.metrics.replay.inc();
+
+        let route = Route::GetReplay {
+            mode: self.mode,
+            score_id: self.score_id,
+        };
+
+        let fut = self
+            .osu
+            .request_stream(Request::new(route))
+            .map_ok(ResponseStream::new);
+
+        Box::pin(fut)
+    }
+}
+
+poll_req!(GetReplayStream => ResponseStream);
+
+/// Get a [`Replay`](crate::model::replay::Replay)
 #[cfg(feature = "replay")]
 #[must_use = "futures do nothing unless you `.await` or poll them"]
 pub struct GetReplay<'a> {
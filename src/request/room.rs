@@ -0,0 +1,126 @@
+use crate::{
+    model::room_::{Room, RoomCategory, RoomLeaderboard, Season},
+    request::{Pending, Query, Request},
+    routing::Route,
+    Osu,
+};
+
+use futures::future::TryFutureExt;
+use serde::Deserialize;
+
+/// Get the [`Room`](crate::model::room::Room) of the current daily challenge.
+///
+/// The room will have its `current_playlist_item` and `host` options filled.
+/// If there is currently no daily challenge, `None` is returned.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct GetDailyChallenge<'a> {
+    fut: Option<Pending<'a, Option<Room>>>,
+    osu: &'a Osu,
+}
+
+impl<'a> GetDailyChallenge<'a> {
+    #[inline]
+    pub(crate) fn new(osu: &'a Osu) -> Self {
+        Self { fut: None, osu }
+    }
+
+    fn start(&mut self) -> Pending<'a, Option<Room>> {
+        #[cfg(feature = "metrics")]
+        self.osu.metrics.daily_challenge.inc();
+
+        let mut query = Query::new();
+        query.push("category", RoomCategory::DailyChallenge);
+        query.push("mode", "active");
+
+        let req = Request::with_query(Route::GetRooms, query);
+
+        let fut = self
+            .osu
+            .request::<Vec<Room>>(req)
+            .map_ok(|rooms| rooms.into_iter().next());
+
+        Box::pin(fut)
+    }
+}
+
+poll_req!(GetDailyChallenge => Option<Room>);
+
+/// Get the [`RoomLeaderboard`](crate::model::room::RoomLeaderboard) of a room.
+///
+/// The contained scores will have their `user` option filled.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct GetRoomLeaderboard<'a> {
+    fut: Option<Pending<'a, RoomLeaderboard>>,
+    osu: &'a Osu,
+    room_id: u64,
+}
+
+impl<'a> GetRoomLeaderboard<'a> {
+    #[inline]
+    pub(crate) fn new(osu: &'a Osu, room_id: u64) -> Self {
+        Self {
+            fut: None,
+            osu,
+            room_id,
+        }
+    }
+
+    fn start(&mut self) -> Pending<'a, RoomLeaderboard> {
+        #[cfg(feature = "metrics")]
+        self.osu.metrics.room_leaderboard.inc();
+
+        let route = Route::GetRoomLeaderboard {
+            room_id: self.room_id,
+        };
+
+        let osu = self.osu;
+        let fut = osu.request::<RoomLeaderboard>(Request::new(route));
+
+        #[cfg(feature = "cache")]
+        let fut = fut.inspect_ok(move |leaderboard| {
+            let scores = leaderboard
+                .leaderboard
+                .iter()
+                .chain(leaderboard.user_score.as_ref());
+
+            for user in scores.filter_map(|score| score.user.as_ref()) {
+                osu.update_cache(user.user_id, &user.username);
+            }
+        });
+
+        Box::pin(fut)
+    }
+}
+
+poll_req!(GetRoomLeaderboard => RoomLeaderboard);
+
+/// Get a vec of [`Season`](crate::model::room::Season)s.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct GetSeasons<'a> {
+    fut: Option<Pending<'a, Vec<Season>>>,
+    osu: &'a Osu,
+}
+
+impl<'a> GetSeasons<'a> {
+    #[inline]
+    pub(crate) fn new(osu: &'a Osu) -> Self {
+        Self { fut: None, osu }
+    }
+
+    fn start(&mut self) -> Pending<'a, Vec<Season>> {
+        #[cfg(feature = "metrics")]
+        self.osu.metrics.seasons.inc();
+
+        let req = Request::new(Route::GetSeasons);
+        let fut = self.osu.request::<Seasons>(req).map_ok(|s| s.seasons);
+
+        Box::pin(fut)
+    }
+}
+
+poll_req!(GetSeasons => Vec<Season>);
+
+#[derive(Deserialize)]
+struct Seasons {
+    seasons: Vec<Season>,
+}
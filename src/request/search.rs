@@ -0,0 +1,83 @@
+use crate::{
+    model::search_::{SearchMode, SearchResult},
+    request::{Pending, Query, Request},
+    routing::Route,
+    Osu,
+};
+
+#[cfg(feature = "cache")]
+use futures::TryFutureExt;
+
+/// Search for [`UserCompact`](crate::model::user::UserCompact)s and
+/// [`WikiPage`](crate::model::wiki::WikiPage)s, wrapped in a
+/// [`SearchResult`](crate::model::search::SearchResult).
+///
+/// If no mode is specified, both users and wiki pages will be searched.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct GetSearch<'a> {
+    fut: Option<Pending<'a, SearchResult>>,
+    osu: &'a Osu,
+    query: String,
+    mode: Option<SearchMode>,
+    page: Option<u32>,
+}
+
+impl<'a> GetSearch<'a> {
+    #[inline]
+    pub(crate) fn new(osu: &'a Osu, query: impl Into<String>) -> Self {
+        Self {
+            fut: None,
+            osu,
+            query: query.into(),
+            mode: None,
+            page: None,
+        }
+    }
+
+    /// Specify whether users, wiki pages, or both should be searched
+    #[inline]
+    pub fn mode(mut self, mode: SearchMode) -> Self {
+        self.mode.replace(mode);
+
+        self
+    }
+
+    /// Specify the page of the results, starting at 1
+    #[inline]
+    pub fn page(mut self, page: u32) -> Self {
+        self.page.replace(page);
+
+        self
+    }
+
+    fn start(&mut self) -> Pending<'a, SearchResult> {
+        #[cfg(feature = "metrics")]
+        self.osu.metrics.search.inc();
+
+        let mut query = Query::new();
+
+        query.push("mode", self.mode.unwrap_or_default());
+        query.push("query", &self.query);
+
+        if let Some(page) = self.page {
+            query.push("page", page);
+        }
+
+        let req = Request::with_query(Route::GetSearch, query);
+        let osu = self.osu;
+        let fut = osu.request::<SearchResult>(req);
+
+        #[cfg(feature = "cache")]
+        let fut = fut.inspect_ok(move |result| {
+            if let Some(ref users) = result.users {
+                for user in users.hits.iter() {
+                    osu.update_cache(user.user_id, &user.username);
+                }
+            }
+        });
+
+        Box::pin(fut)
+    }
+}
+
+poll_req!(GetSearch => SearchResult);
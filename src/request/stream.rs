@@ -0,0 +1,335 @@
+use crate::{error::OsuError, OsuResult};
+
+use bytes::Bytes;
+use futures::stream::Stream;
+use hyper::body::{Body as HyperBody, HttpBody};
+use serde::de::DeserializeOwned;
+use std::{
+    fmt::{Debug, Formatter, Result as FmtResult},
+    marker::PhantomData,
+    ops::Range,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// The body of a response, streamed in chunks instead of buffering it entirely.
+///
+/// Retrieved through e.g. [`GetBeatmapsetDownload`](crate::request::GetBeatmapsetDownload)
+/// or [`GetReplayStream`](crate::request::GetReplayStream).
+pub struct ResponseStream {
+    body: HyperBody,
+}
+
+impl ResponseStream {
+    pub(crate) fn new(body: HyperBody) -> Self {
+        Self { body }
+    }
+
+    /// The size of the body in bytes if the server provided it
+    #[inline]
+    pub fn content_length(&self) -> Option<u64> {
+        self.body.size_hint().exact()
+    }
+}
+
+impl Stream for ResponseStream {
+    type Item = OsuResult<Bytes>;
+
+    #[inline]
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Pin::new(&mut self.body)
+            .poll_data(cx)
+            .map_err(|source| OsuError::ChunkingResponse { source })
+    }
+}
+
+impl Debug for ResponseStream {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("ResponseStream")
+            .field("content_length", &self.content_length())
+            .finish()
+    }
+}
+
+/// Deserializes the elements of a JSON array within a response one by one
+/// while the response is still being received.
+///
+/// Only the bytes of the current element are buffered, everything
+/// outside of the array is skipped.
+///
+/// Retrieved through e.g. [`GetMatchEvents`](crate::request::GetMatchEvents).
+pub struct JsonArrayStream<T> {
+    inner: ResponseStream,
+    scanner: ArrayScanner,
+    done: bool,
+    item: PhantomData<fn() -> T>,
+}
+
+impl<T> JsonArrayStream<T> {
+    /// Deserialize the array of the given field of the top-level object
+    /// or the top-level array itself if `key` is `None`.
+    pub(crate) fn new(inner: ResponseStream, key: Option<&'static str>) -> Self {
+        Self {
+            inner,
+            scanner: ArrayScanner::new(key),
+            done: false,
+            item: PhantomData,
+        }
+    }
+}
+
+impl<T: DeserializeOwned> Stream for JsonArrayStream<T> {
+    type Item = OsuResult<T>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            if let Some(range) = self.scanner.next_element() {
+                let bytes = &self.scanner.buf[range.clone()];
+
+                let res = serde_json::from_slice(bytes).map_err(|source| {
+                    let body = String::from_utf8_lossy(bytes).into_owned();
+
+                    OsuError::Parsing { body, source }
+                });
+
+                self.scanner.consume(range.end);
+
+                return Poll::Ready(Some(res));
+            }
+
+            if self.done || self.scanner.finished() {
+                return Poll::Ready(None);
+            }
+
+            match Pin::new(&mut self.inner).poll_next(cx) {
+                Poll::Ready(Some(Ok(chunk))) => self.scanner.push(&chunk),
+                Poll::Ready(Some(Err(err))) => {
+                    self.done = true;
+
+                    return Poll::Ready(Some(Err(err)));
+                }
+                Poll::Ready(None) => self.done = true,
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+impl<T> Debug for JsonArrayStream<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("JsonArrayStream")
+            .field("inner", &self.inner)
+            .field("buffered", &self.scanner.buf.len())
+            .finish()
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum ScanState {
+    /// Looking for the array
+    Searching,
+    /// Inside the array, possibly inside an element that started at the given index
+    InArray { start: Option<usize> },
+    /// The array was closed
+    Finished,
+}
+
+/// Finds the boundaries of array elements in JSON that arrives in chunks
+struct ArrayScanner {
+    key: Option<&'static str>,
+    buf: Vec<u8>,
+    /// Index of the next byte to scan
+    pos: usize,
+    depth: usize,
+    in_string: bool,
+    escaped: bool,
+    /// The most recent string of the top-level object, potentially a key
+    last_string: Vec<u8>,
+    state: ScanState,
+}
+
+impl ArrayScanner {
+    fn new(key: Option<&'static str>) -> Self {
+        Self {
+            key,
+            buf: Vec::new(),
+            pos: 0,
+            depth: 0,
+            in_string: false,
+            escaped: false,
+            last_string: Vec::new(),
+            state: ScanState::Searching,
+        }
+    }
+
+    fn push(&mut self, chunk: &[u8]) {
+        self.buf.extend_from_slice(chunk);
+    }
+
+    fn finished(&self) -> bool {
+        self.state == ScanState::Finished
+    }
+
+    /// The depth of the array's elements
+    fn element_depth(&self) -> usize {
+        match self.key {
+            Some(_) => 2,
+            None => 1,
+        }
+    }
+
+    /// Whether the most recent string is the key of the array
+    fn is_target_key(&self) -> bool {
+        match self.key {
+            Some(key) => key.as_bytes() == self.last_string,
+            None => true,
+        }
+    }
+
+    /// Remove all bytes up to `end` from the buffer
+    fn consume(&mut self, end: usize) {
+        self.buf.drain(..end);
+        self.pos -= end;
+
+        if let ScanState::InArray { start: Some(_) } = self.state {
+            self.state = ScanState::InArray { start: None };
+        }
+    }
+
+    /// Scan the buffered bytes and return the range of the next complete element
+    fn next_element(&mut self) -> Option<Range<usize>> {
+        let element_depth = self.element_depth();
+
+        while self.pos < self.buf.len() {
+            let idx = self.pos;
+            let byte = self.buf[idx];
+            self.pos += 1;
+
+            let recording = self.state == ScanState::Searching && self.depth == 1;
+
+            if self.in_string {
+                if self.escaped {
+                    self.escaped = false;
+                } else if byte == b'\\' {
+                    self.escaped = true;
+                } else if byte == b'"' {
+                    self.in_string = false;
+                } else if recording {
+                    self.last_string.push(byte);
+                }
+
+                continue;
+            }
+
+            if self.state == (ScanState::InArray { start: None })
+                && self.depth == element_depth
+                && !matches!(byte, b',' | b']')
+                && !byte.is_ascii_whitespace()
+            {
+                self.state = ScanState::InArray { start: Some(idx) };
+            }
+
+            match byte {
+                b'"' => {
+                    self.in_string = true;
+
+                    if recording {
+                        self.last_string.clear();
+                    }
+                }
+                b'[' if self.state == ScanState::Searching
+                    && self.depth + 1 == element_depth
+                    && self.is_target_key() =>
+                {
+                    self.depth += 1;
+                    self.state = ScanState::InArray { start: None };
+                }
+                b'{' | b'[' => self.depth += 1,
+                b'}' | b']' => {
+                    self.depth = self.depth.saturating_sub(1);
+
+                    if let ScanState::InArray { start } = self.state {
+                        if self.depth + 1 == element_depth {
+                            self.state = ScanState::Finished;
+
+                            if let Some(start) = start {
+                                return Some(start..idx);
+                            }
+                        }
+                    }
+                }
+                b',' => {
+                    if let ScanState::InArray { start: Some(start) } = self.state {
+                        if self.depth == element_depth {
+                            return Some(start..idx);
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        // Scanned bytes outside of the current element are not needed anymore
+        match self.state {
+            ScanState::InArray { start: Some(start) } => {
+                self.buf.drain(..start);
+                self.pos -= start;
+                self.state = ScanState::InArray { start: Some(0) };
+            }
+            _ => {
+                self.buf.clear();
+                self.pos = 0;
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn elements(json: &str, key: Option<&'static str>, chunk_size: usize) -> Vec<String> {
+        let mut scanner = ArrayScanner::new(key);
+        let mut elements = Vec::new();
+
+        for chunk in json.as_bytes().chunks(chunk_size) {
+            scanner.push(chunk);
+
+            while let Some(range) = scanner.next_element() {
+                let element = std::str::from_utf8(&scanner.buf[range.clone()]).unwrap();
+                elements.push(element.trim().to_owned());
+                scanner.consume(range.end);
+            }
+        }
+
+        elements
+    }
+
+    #[test]
+    fn top_level_array() {
+        let json = r#"[1, "a,]", {"b": [2, 3]}, [4]]"#;
+        let expected = ["1", r#""a,]""#, r#"{"b": [2, 3]}"#, "[4]"];
+
+        for chunk_size in 1..json.len() {
+            assert_eq!(elements(json, None, chunk_size), expected);
+        }
+    }
+
+    #[test]
+    fn array_of_key() {
+        let json = r#"{"match": {"events": [0]}, "name": "events", "events": [{"id": 1, "s": "\"}"}, {"id": 2}], "users": [3]}"#;
+        let expected = [r#"{"id": 1, "s": "\"}"}"#, r#"{"id": 2}"#];
+
+        for chunk_size in 1..json.len() {
+            assert_eq!(elements(json, Some("events"), chunk_size), expected);
+        }
+    }
+
+    #[test]
+    fn empty_array() {
+        assert!(elements(r#"{"events": []}"#, Some("events"), 3).is_empty());
+        assert!(elements("[ ]", None, 1).is_empty());
+    }
+}
@@ -0,0 +1,86 @@
+use crate::{
+    model::{team_::Team, user_::UserCompact},
+    request::{Pending, Request},
+    routing::Route,
+    Osu,
+};
+
+#[cfg(feature = "cache")]
+use futures::TryFutureExt;
+
+/// Get a [`Team`](crate::model::team::Team).
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct GetTeam<'a> {
+    fut: Option<Pending<'a, Team>>,
+    osu: &'a Osu,
+    team_id: u32,
+}
+
+impl<'a> GetTeam<'a> {
+    #[inline]
+    pub(crate) fn new(osu: &'a Osu, team_id: u32) -> Self {
+        Self {
+            fut: None,
+            osu,
+            team_id,
+        }
+    }
+
+    fn start(&mut self) -> Pending<'a, Team> {
+        #[cfg(feature = "metrics")]
+        self.osu.metrics.team.inc();
+
+        let route = Route::GetTeam {
+            team_id: self.team_id,
+        };
+
+        Box::pin(self.osu.request(Request::new(route)))
+    }
+}
+
+poll_req!(GetTeam => Team);
+
+/// Get the members of a team in form of a vec of
+/// [`UserCompact`](crate::model::user::UserCompact)s.
+///
+/// The users will have the `team` option filled.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct GetTeamMembers<'a> {
+    fut: Option<Pending<'a, Vec<UserCompact>>>,
+    osu: &'a Osu,
+    team_id: u32,
+}
+
+impl<'a> GetTeamMembers<'a> {
+    #[inline]
+    pub(crate) fn new(osu: &'a Osu, team_id: u32) -> Self {
+        Self {
+            fut: None,
+            osu,
+            team_id,
+        }
+    }
+
+    fn start(&mut self) -> Pending<'a, Vec<UserCompact>> {
+        #[cfg(feature = "metrics")]
+        self.osu.metrics.team_members.inc();
+
+        let route = Route::GetTeamMembers {
+            team_id: self.team_id,
+        };
+
+        let osu = self.osu;
+        let fut = osu.request::<Vec<UserCompact>>(Request::new(route));
+
+        #[cfg(feature = "cache")]
+        let fut = fut.inspect_ok(move |users| {
+            for user in users.iter() {
+                osu.update_cache(user.user_id, &user.username);
+            }
+        });
+
+        Box::pin(fut)
+    }
+}
+
+poll_req!(GetTeamMembers => Vec<UserCompact>);
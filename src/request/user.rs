@@ -17,8 +17,10 @@ use crate::{
 use smallstr::SmallString;
 use std::fmt;
 
+use futures::future::TryFutureExt;
+
 #[cfg(feature = "cache")]
-use {futures::future::TryFutureExt, std::mem};
+use std::mem;
 
 /// Either a user id as u32 or a username as String.
 ///
@@ -114,7 +116,10 @@ impl<'a> GetOwnData<'a> {
 
         let req = Request::new(Route::GetOwnData { mode: self.mode });
         let osu = self.osu;
-        let fut = osu.request::<User>(req);
+
+        let fut = osu
+            .request::<User>(req)
+            .inspect_ok(move |user| osu.set_own_user_id(user.user_id));
 
         #[cfg(feature = "cache")]
         let fut = fut.inspect_ok(move |user| osu.update_cache(user.user_id, &user.username));
@@ -125,6 +130,85 @@ impl<'a> GetOwnData<'a> {
 
 poll_req!(GetOwnData => User);
 
+/// Get a vec of [`Beatmapset`](crate::model::beatmap::Beatmapset)s
+/// the authenticated user favourited.
+///
+/// Note that the client has to be initialized with the `identify` scope
+/// through the OAuth process in order for this endpoint to not return an error.
+///
+/// See [`OsuBuilder::with_authorization`](crate::OsuBuilder::with_authorization).
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct GetOwnFavourites<'a> {
+    fut: Option<Pending<'a, Vec<Beatmapset>>>,
+    osu: &'a Osu,
+    limit: Option<usize>,
+    offset: Option<usize>,
+}
+
+impl<'a> GetOwnFavourites<'a> {
+    #[inline]
+    pub(crate) fn new(osu: &'a Osu) -> Self {
+        Self {
+            fut: None,
+            osu,
+            limit: None,
+            offset: None,
+        }
+    }
+
+    /// Limit the amount of results in the response
+    #[inline]
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit.replace(limit);
+
+        self
+    }
+
+    /// Set an offset for the requested elements
+    /// e.g. skip the first `offset` amount in the list
+    #[inline]
+    pub fn offset(mut self, offset: usize) -> Self {
+        self.offset.replace(offset);
+
+        self
+    }
+
+    fn start(&mut self) -> Pending<'a, Vec<Beatmapset>> {
+        #[cfg(feature = "metrics")]
+        self.osu.metrics.own_favourites.inc();
+
+        let mut query = Query::new();
+
+        if let Some(limit) = self.limit {
+            query.push("limit", limit);
+        }
+
+        if let Some(offset) = self.offset {
+            query.push("offset", offset);
+        }
+
+        let osu = self.osu;
+
+        let req = move |user_id| {
+            let route = Route::GetUserBeatmapsets {
+                user_id,
+                map_type: "favourite",
+            };
+
+            osu.request(Request::with_query(route, query))
+        };
+
+        // The endpoint requires the user id so it has to be retrieved first
+        // unless the client already knows it from a previous request
+        match osu.own_user_id() {
+            Some(user_id) => Box::pin(req(user_id)),
+            None => Box::pin(GetOwnData::new(osu).and_then(move |user| req(user.user_id))),
+        }
+    }
+}
+
+poll_req!(GetOwnFavourites => Vec<Beatmapset>);
+
 /// Get a [`User`](crate::model::user::User) by their id.
 #[must_use = "futures do nothing unless you `.await` or poll them"]
 pub struct GetUser<'a> {
@@ -1,14 +1,18 @@
 use crate::{
-    model::wiki_::WikiPage,
+    client::parse_bytes,
+    model::wiki_::WikiResponse,
     request::{Pending, Request},
     routing::Route,
     Osu,
 };
 
-/// Get a [`WikiPage`](crate::model::wiki::WikiPage) or image data.
+use futures::future::TryFutureExt;
+
+/// Get a [`WikiPage`](crate::model::wiki::WikiPage) or image data
+/// wrapped in a [`WikiResponse`](crate::model::wiki::WikiResponse).
 #[must_use = "futures do nothing unless you `.await` or poll them"]
 pub struct GetWikiPage<'a> {
-    fut: Option<Pending<'a, WikiPage>>, // TODO: Make this enum; either WikiPage or binary blob
+    fut: Option<Pending<'a, WikiResponse>>,
     osu: &'a Osu,
     locale: Option<String>,
     page: Option<String>,
@@ -33,7 +37,7 @@ impl<'a> GetWikiPage<'a> {
         self
     }
 
-    fn start(&mut self) -> Pending<'a, WikiPage> {
+    fn start(&mut self) -> Pending<'a, WikiResponse> {
         #[cfg(feature = "metrics")]
         self.osu.metrics.wiki.inc();
 
@@ -42,8 +46,23 @@ impl<'a> GetWikiPage<'a> {
             page: self.page.take(),
         });
 
-        Box::pin(self.osu.request(req))
+        let fut = self.osu.request_raw_with_content_type(req).and_then(
+            |(content_type, bytes)| async move {
+                // Articles are JSON, anything else is image data
+                let content_type = content_type.as_ref().and_then(|value| value.to_str().ok());
+                let is_json =
+                    matches!(content_type, Some(value) if value.starts_with("application/json"));
+
+                if is_json {
+                    parse_bytes(bytes).map(WikiResponse::Page)
+                } else {
+                    Ok(WikiResponse::Image(bytes.to_vec()))
+                }
+            },
+        );
+
+        Box::pin(fut)
     }
 }
 
-poll_req!(GetWikiPage => WikiPage);
+poll_req!(GetWikiPage => WikiResponse);
@@ -15,6 +15,10 @@ pub(crate) enum Route {
     GetBeatmapDifficultyAttributes {
         map_id: u32,
     },
+    GetBeatmapPack {
+        tag: String,
+    },
+    GetBeatmapPacks,
     GetBeatmapScores {
         map_id: u32,
     },
@@ -29,13 +33,21 @@ pub(crate) enum Route {
     GetBeatmapset {
         mapset_id: u32,
     },
+    GetBeatmapsetDownload {
+        mapset_id: u32,
+    },
     GetBeatmapsetFromMapId,
     GetBeatmapsetEvents,
+    PostBeatmapsetFavourite {
+        mapset_id: u32,
+    },
     GetBeatmapsetSearch,
     GetComments,
+    GetEvents,
     GetForumPosts {
         topic_id: u64,
     },
+    GetKudosuRankings,
     GetMatch {
         match_id: Option<u32>,
     },
@@ -52,6 +64,10 @@ pub(crate) enum Route {
     GetRecentEvents {
         user_id: u32,
     },
+    GetRoomLeaderboard {
+        room_id: u64,
+    },
+    GetRooms,
     GetReplay {
         mode: GameMode,
         score_id: u64,
@@ -60,8 +76,16 @@ pub(crate) enum Route {
         mode: GameMode,
         score_id: u64,
     },
+    GetSearch,
     GetSeasonalBackgrounds,
+    GetSeasons,
     GetSpotlights,
+    GetTeam {
+        team_id: u32,
+    },
+    GetTeamMembers {
+        team_id: u32,
+    },
     GetUser {
         user_id: UserId,
         mode: Option<GameMode>,
@@ -83,6 +107,10 @@ pub(crate) enum Route {
         locale: String,
         page: Option<String>,
     },
+    UpdateBeatmapsetWatch {
+        mapset_id: u32,
+        watch: bool,
+    },
 }
 
 impl Route {
@@ -94,6 +122,8 @@ impl Route {
             Self::GetBeatmapDifficultyAttributes { map_id } => {
                 (Method::POST, format!("beatmaps/{map_id}/attributes").into())
             }
+            Self::GetBeatmapPack { tag } => (Method::GET, format!("beatmaps/packs/{tag}").into()),
+            Self::GetBeatmapPacks => (Method::GET, "beatmaps/packs".into()),
             Self::GetBeatmapScores { map_id } => {
                 (Method::GET, format!("beatmaps/{}/scores", map_id).into())
             }
@@ -108,13 +138,23 @@ impl Route {
             Self::GetBeatmapset { mapset_id } => {
                 (Method::GET, format!("beatmapsets/{}", mapset_id).into())
             }
+            Self::GetBeatmapsetDownload { mapset_id } => (
+                Method::GET,
+                format!("beatmapsets/{}/download", mapset_id).into(),
+            ),
             Self::GetBeatmapsetFromMapId => (Method::GET, "beatmapsets/lookup".into()),
             Self::GetBeatmapsetEvents => (Method::GET, "beatmapsets/events".into()),
+            Self::PostBeatmapsetFavourite { mapset_id } => (
+                Method::POST,
+                format!("beatmapsets/{}/favourites", mapset_id).into(),
+            ),
             Self::GetBeatmapsetSearch => (Method::GET, "beatmapsets/search".into()),
             Self::GetComments => (Method::GET, "comments".into()),
+            Self::GetEvents => (Method::GET, "events".into()),
             Self::GetForumPosts { topic_id } => {
                 (Method::GET, format!("forums/topics/{}", topic_id).into())
             }
+            Self::GetKudosuRankings => (Method::GET, "rankings/kudosu".into()),
             Self::GetMatch { match_id } => {
                 let path = match match_id {
                     Some(id) => format!("matches/{}", id).into(),
@@ -147,6 +187,10 @@ impl Route {
                 Method::GET,
                 format!("users/{}/recent_activity", user_id).into(),
             ),
+            Self::GetRoomLeaderboard { room_id } => {
+                (Method::GET, format!("rooms/{}/leaderboard", room_id).into())
+            }
+            Self::GetRooms => (Method::GET, "rooms".into()),
             Self::GetReplay { mode, score_id } => (
                 Method::GET,
                 format!("scores/{}/{}/download", mode, score_id).into(),
@@ -154,8 +198,14 @@ impl Route {
             Self::GetScore { mode, score_id } => {
                 (Method::GET, format!("scores/{}/{}", mode, score_id).into())
             }
+            Self::GetSearch => (Method::GET, "search".into()),
             Self::GetSeasonalBackgrounds => (Method::GET, "seasonal-backgrounds".into()),
+            Self::GetSeasons => (Method::GET, "seasons".into()),
             Self::GetSpotlights => (Method::GET, "spotlights".into()),
+            Self::GetTeam { team_id } => (Method::GET, format!("teams/{}", team_id).into()),
+            Self::GetTeamMembers { team_id } => {
+                (Method::GET, format!("teams/{}/members", team_id).into())
+            }
             Self::GetUser { user_id, mode } => {
                 let mut path = format!("users/{}", user_id);
 
@@ -189,6 +239,11 @@ impl Route {
 
                 (Method::GET, path.into())
             }
+            Self::UpdateBeatmapsetWatch { mapset_id, watch } => {
+                let method = if watch { Method::PUT } else { Method::DELETE };
+
+                (method, format!("beatmapsets/watches/{}", mapset_id).into())
+            }
         }
     }
 }
@@ -7,19 +7,21 @@ use std::{
 
 use dotenv::dotenv;
 use eyre::{Result, WrapErr};
+use futures::{StreamExt, TryStreamExt};
 use once_cell::sync::OnceCell;
 use rosu_v2::{
     model::{
-        beatmap::{BeatmapsetSearchSort, RankStatus},
-        GameMode,
+        beatmap::{BeatmapPackType, BeatmapsetEventType, BeatmapsetSearchSort, RankStatus},
+        ranking::RankingVariant,
+        recent_event::EventSort,
+        score::LeaderboardType,
+        search::SearchMode,
+        GameMode, GameMods,
     },
     Osu,
 };
 use tokio::sync::{Mutex, MutexGuard};
 
-#[cfg(feature = "cache")]
-use rosu_v2::model::GameMods;
-
 struct OsuSingleton {
     initialized: AtomicBool,
     // The mutex is necessary since each test spawns its own async executor and hyper's Client
@@ -79,6 +81,8 @@ const SYLAS: u32 = 3906405;
 
 const DE_VS_CA: u32 = 71028303;
 
+const TEAM: u32 = 1;
+
 const COOKIEZI_FREEDOM_DIVE: u64 = 2177560145;
 
 #[tokio::test]
@@ -104,6 +108,36 @@ async fn beatmap() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn beatmaps_by_checksums() -> Result<()> {
+    let osu = OSU.get().await?;
+    let map = osu.beatmap().map_id(ADESSO_BALLA).await?;
+    let checksum = map.checksum.clone().unwrap();
+    let missing = "00000000000000000000000000000000";
+
+    let upper = checksum.to_ascii_uppercase();
+
+    let maps = osu
+        .beatmaps_by_checksums([checksum.as_str(), upper.as_str(), missing])
+        .await?;
+
+    assert_eq!(maps.len(), 2);
+    assert_eq!(
+        maps[&checksum]
+            .as_ref()
+            .unwrap()
+            .as_ref()
+            .map(|map| map.map_id),
+        Some(ADESSO_BALLA)
+    );
+    assert!(matches!(maps[missing], Ok(None)));
+
+    #[cfg(feature = "cache")]
+    osu.clear_missing_checksums();
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn beatmap_difficulty_attributes() -> Result<()> {
     let attrs = OSU
@@ -118,6 +152,53 @@ async fn beatmap_difficulty_attributes() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn beatmap_difficulty_attributes_batch() -> Result<()> {
+    let mods = [
+        GameMods::NoMod,
+        GameMods::Hidden,
+        GameMods::HardRock,
+        GameMods::DoubleTime,
+        GameMods::Hidden | GameMods::DoubleTime,
+    ];
+
+    let attrs = OSU
+        .get()
+        .await?
+        .beatmap_difficulty_attributes_batch(ADESSO_BALLA, mods)
+        .await?;
+
+    assert_eq!(attrs.len(), mods.len());
+    assert_eq!(attrs[&GameMods::NoMod], attrs[&GameMods::Hidden]);
+    assert_eq!(
+        attrs[&GameMods::DoubleTime],
+        attrs[&(GameMods::Hidden | GameMods::DoubleTime)]
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn beatmap_packs() -> Result<()> {
+    let osu = OSU.get().await?;
+    let packs = osu
+        .beatmap_packs()
+        .pack_type(BeatmapPackType::Loved)
+        .await?;
+    println!("Received {} loved packs", packs.packs.len());
+
+    let tag = &packs.packs.first().expect("no packs").tag;
+    let pack = osu.beatmap_pack(tag).await?;
+
+    println!(
+        "Pack {} contains {} mapsets",
+        pack.name,
+        pack.mapsets.map_or(0, |mapsets| mapsets.len())
+    );
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn beatmaps() -> Result<()> {
     let maps = OSU
@@ -133,8 +214,15 @@ async fn beatmaps() -> Result<()> {
 
 #[tokio::test]
 async fn beatmap_scores() -> Result<()> {
-    let scores = OSU.get().await?.beatmap_scores(ADESSO_BALLA).await?;
-    println!("Received {} scores", scores.len());
+    let leaderboard = OSU
+        .get()
+        .await?
+        .beatmap_scores(ADESSO_BALLA)
+        .leaderboard_type(LeaderboardType::Global)
+        .limit(100)
+        .await?;
+
+    println!("Received {} scores", leaderboard.scores.len());
 
     Ok(())
 }
@@ -179,6 +267,47 @@ async fn beatmapset() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn beatmapset_download() -> Result<()> {
+    let mut download = OSU
+        .get()
+        .await?
+        .beatmapset_download(HIKOUI_GUMO)
+        .no_video(true)
+        .await?;
+
+    let mut len = 0;
+
+    while let Some(chunk) = download.try_next().await? {
+        len += chunk.len();
+    }
+
+    println!("Received archive with {} bytes", len);
+
+    Ok(())
+}
+
+#[tokio::test]
+#[ignore = "requires OAuth to not throw an error"]
+async fn beatmapset_favourite() -> Result<()> {
+    let osu = OSU.get().await?;
+    let favourited = osu.favourite_beatmapset(HIKOUI_GUMO).await?;
+    let unfavourited = osu.unfavourite_beatmapset(HIKOUI_GUMO).await?;
+    assert_eq!(favourited, unfavourited + 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+#[ignore = "requires OAuth to not throw an error"]
+async fn beatmapset_watch() -> Result<()> {
+    let osu = OSU.get().await?;
+    osu.watch_beatmapset(HIKOUI_GUMO).await?;
+    osu.unwatch_beatmapset(HIKOUI_GUMO).await?;
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn beatmapset_from_map_id() -> Result<()> {
     let mapset = OSU
@@ -204,6 +333,31 @@ async fn beatmapset_events() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn beatmapset_events_stream() -> Result<()> {
+    let osu = OSU.get().await?;
+
+    let pages: Vec<_> = osu
+        .beatmapset_events()
+        .user(BADEWANNE3)
+        .types(&[BeatmapsetEventType::Nominate, BeatmapsetEventType::Qualify])
+        .sort(EventSort::IdAscending)
+        .limit(5)
+        .into_stream()
+        .take(2)
+        .try_collect()
+        .await?;
+
+    for page in pages {
+        assert!(page.events.iter().all(|event| matches!(
+            event.event_type(),
+            BeatmapsetEventType::Nominate | BeatmapsetEventType::Qualify
+        )));
+    }
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn beatmapset_search() -> Result<()> {
     let search_result = OSU
@@ -238,6 +392,40 @@ async fn comments() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn daily_challenge() -> Result<()> {
+    let osu = OSU.get().await?;
+
+    let room = match osu.daily_challenge().await? {
+        Some(room) => room,
+        None => return Ok(()),
+    };
+
+    let leaderboard = osu.room_leaderboard(room.room_id).await?;
+
+    println!(
+        "Received daily challenge \"{}\" with {} leaderboard entries",
+        room.name,
+        leaderboard.leaderboard.len()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn events() -> Result<()> {
+    let osu = OSU.get().await?;
+    let events = osu.events().sort(EventSort::IdAscending).await?;
+    println!("Received {} events", events.events.len());
+
+    #[cfg(not(feature = "rkyv"))]
+    if let Some(next) = events.get_next(&osu).await {
+        println!("Received {} more events", next?.events.len());
+    }
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn chart_rankings() -> Result<()> {
     let rankings = OSU.get().await?.chart_rankings(GameMode::Osu).await?;
@@ -320,6 +508,20 @@ async fn kudosu() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn kudosu_rankings() -> Result<()> {
+    let rankings = OSU.get().await?.kudosu_rankings().page(2).await?;
+    assert_eq!(rankings.next_page, Some(3));
+
+    println!(
+        "Received {} users of the kudosu rankings, the first one having {} kudosu",
+        rankings.ranking.len(),
+        rankings.ranking[0].kudosu.map_or(0, |kudosu| kudosu.total)
+    );
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn news() -> Result<()> {
     let news = OSU.get().await?.news().await?;
@@ -340,6 +542,17 @@ async fn osu_match() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn osu_match_events() -> Result<()> {
+    let osu = OSU.get().await?;
+    let osu_match = osu.osu_match(DE_VS_CA).await?;
+    let events: Vec<_> = osu.osu_match_events(DE_VS_CA).await?.try_collect().await?;
+
+    assert_eq!(events, osu_match.events);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn osu_matches() -> Result<()> {
     let osu_matches = OSU.get().await?.osu_matches().await?;
@@ -361,6 +574,15 @@ async fn own_data() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+#[ignore = "requires OAuth to not throw an error"]
+async fn own_favourites() -> Result<()> {
+    let mapsets = OSU.get().await?.own_favourites().limit(5).offset(2).await?;
+    println!("Received {} favourited mapsets", mapsets.len());
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn performance_rankings() -> Result<()> {
     let rankings = OSU
@@ -397,7 +619,13 @@ async fn score() -> Result<()> {
 
 #[tokio::test]
 async fn score_rankings() -> Result<()> {
-    let rankings = OSU.get().await?.score_rankings(GameMode::Osu).await?;
+    let rankings = OSU
+        .get()
+        .await?
+        .score_rankings(GameMode::Mania)
+        .country("de")
+        .variant(RankingVariant::FourKey)
+        .await?;
 
     println!(
         "Received score rankings with {} out of {} users",
@@ -408,6 +636,28 @@ async fn score_rankings() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn score_rankings_stream() -> Result<()> {
+    let osu = OSU.get().await?;
+
+    let pages: Vec<_> = osu
+        .score_rankings(GameMode::Osu)
+        .country("be")
+        .page(199)
+        .into_stream()
+        .try_collect()
+        .await?;
+
+    assert!(pages.len() <= 2);
+
+    println!(
+        "Received the last {} pages of belgian score rankings",
+        pages.len()
+    );
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn seasonal_backgrounds() -> Result<()> {
     let backgrounds = OSU.get().await?.seasonal_backgrounds().await?;
@@ -416,6 +666,14 @@ async fn seasonal_backgrounds() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn seasons() -> Result<()> {
+    let seasons = OSU.get().await?.seasons().await?;
+    println!("Received {} seasons", seasons.len());
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn spotlights() -> Result<()> {
     let spotlights = OSU.get().await?.spotlights().await?;
@@ -434,6 +692,37 @@ async fn spotlights() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn team() -> Result<()> {
+    let osu = OSU.get().await?;
+    let team = osu.team(TEAM).await?;
+    let members = osu.team_members(TEAM).await?;
+
+    assert!(members
+        .iter()
+        .all(|member| matches!(member.team, Some(ref t) if t.team_id == team.team_id)));
+
+    println!("Received {} members of team {}", members.len(), team.name);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn team_rankings() -> Result<()> {
+    let osu = OSU.get().await?;
+    let rankings = osu.team_rankings(GameMode::Taiko).await?;
+    let next = rankings.get_next(&osu, GameMode::Taiko).await.transpose()?;
+
+    println!(
+        "Received team rankings with {} out of {} teams, the best being {}",
+        rankings.ranking.len() + next.map_or(0, |next| next.ranking.len()),
+        rankings.total,
+        rankings.ranking[0].team.name
+    );
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn user() -> Result<()> {
     let user = OSU
@@ -511,6 +800,22 @@ async fn users() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn search() -> Result<()> {
+    let result = OSU
+        .get()
+        .await?
+        .search("badewanne")
+        .mode(SearchMode::User)
+        .await?;
+
+    let users = result.users.expect("missing users");
+
+    println!("Received {} out of {} users", users.hits.len(), users.total);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn wiki() -> Result<()> {
     let page = OSU
@@ -518,7 +823,9 @@ async fn wiki() -> Result<()> {
         .await?
         .wiki("fr")
         .page("Client/File_formats/Osu_%28file_format%29")
-        .await?;
+        .await?
+        .page()
+        .expect("expected wiki article");
 
     println!(
         "Received page {}/{}: {}",
@@ -527,3 +834,19 @@ async fn wiki() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn wiki_image() -> Result<()> {
+    let image = OSU
+        .get()
+        .await?
+        .wiki("en")
+        .page("Hit_object/img/hit-circle.jpg")
+        .await?
+        .image()
+        .expect("expected image data");
+
+    println!("Received image of {} bytes", image.len());
+
+    Ok(())
+}
@@ -1,6 +1,6 @@
 extern crate rosu_v2;
 
-use rosu_v2::prelude::*;
+use rosu_v2::{model::team::Team as PlayerTeam, prelude::*};
 use serde::{de::DeserializeOwned, Serialize};
 use std::{collections::HashMap, fmt::Debug};
 use time::{Date, Duration, OffsetDateTime};
@@ -512,6 +512,32 @@ fn get_match_score() -> MatchScore {
     }
 }
 
+fn get_room() -> Room {
+    Room {
+        active: true,
+        category: RoomCategory::DailyChallenge,
+        current_playlist_item: Some(PlaylistItem {
+            allowed_mods: "HDHR".parse().unwrap(),
+            beatmap: Some(get_map_compact()),
+            expired: false,
+            item_id: 123,
+            map_id: 456,
+            mode: GameMode::Osu,
+            owner_id: 2,
+            played_at: None,
+            required_mods: GameModsIntermediate::from(GameMods::DoubleTime),
+            room_id: 789,
+        }),
+        ends_at: Some(get_date()),
+        host: Some(get_user_compact()),
+        name: "Daily Challenge: 2024-07-25".to_owned(),
+        participant_count: 10_000,
+        room_id: 789,
+        starts_at: get_date(),
+        user_id: 2,
+    }
+}
+
 fn get_score() -> Score {
     Score {
         accuracy: 98.76,
@@ -521,21 +547,27 @@ fn get_score() -> Score {
         map: Some(get_map()),
         mapset: Some(get_mapset_compact()),
         mode: GameMode::Catch,
-        mods: GameMods::Hidden | GameMods::DoubleTime,
+        mods: GameModsIntermediate::from(GameMods::Hidden | GameMods::DoubleTime),
         perfect: false,
         pp: Some(456.78),
         rank_country: Some(1),
         rank_global: Some(10),
         replay: Some(true),
+        maximum_statistics: Some(LazerScoreStatistics {
+            great: 1001,
+            large_tick_hit: 300,
+            small_tick_hit: 202,
+            ..Default::default()
+        }),
         score: 12_345_678,
         score_id: Some(123_456_789_000),
-        statistics: ScoreStatistics {
-            count_geki: 1,
-            count_300: 1000,
-            count_katu: 2,
-            count_100: 300,
-            count_50: 200,
-            count_miss: 1,
+        lazer_statistics: LazerScoreStatistics {
+            great: 1000,
+            miss: 1,
+            large_tick_hit: 300,
+            small_tick_hit: 200,
+            small_tick_miss: 2,
+            ..Default::default()
         },
         user: Some(get_user_compact()),
         user_id: 2,
@@ -546,6 +578,38 @@ fn get_score() -> Score {
     }
 }
 
+fn get_beatmap_scores() -> BeatmapScores {
+    BeatmapScores {
+        scores: vec![get_score(), get_score()],
+        user_score: Some(BeatmapUserScore {
+            pos: 42,
+            score: get_score(),
+        }),
+    }
+}
+
+fn get_search_result() -> SearchResult {
+    SearchResult {
+        users: Some(SearchHits {
+            hits: vec![get_user_compact()],
+            total: 42,
+        }),
+        wiki_pages: Some(SearchHits {
+            hits: vec![WikiPage {
+                available_locales: vec!["en".to_owned(), "de".to_owned()],
+                layout: "markdown_page".to_owned(),
+                locale: "en".to_owned(),
+                markdown: "# Title\n\nContent".to_owned(),
+                path: "Hit_object".to_owned(),
+                subtitle: None,
+                tags: vec!["tag".to_owned()],
+                title: "Hit object".to_owned(),
+            }],
+            total: 1,
+        }),
+    }
+}
+
 fn get_seasonal_backgrounds() -> SeasonalBackgrounds {
     SeasonalBackgrounds {
         ends_at: get_date(),
@@ -568,6 +632,37 @@ fn get_spotlight() -> Spotlight {
     }
 }
 
+fn get_kudosu_rankings() -> KudosuRankings {
+    KudosuRankings {
+        next_page: Some(2),
+        ranking: vec![get_user_compact()],
+    }
+}
+
+fn get_team() -> PlayerTeam {
+    PlayerTeam {
+        flag_url: Some("https://assets.ppy.sh/teams/flag/1/flag.png".to_owned()),
+        team_id: 1,
+        name: "Team Name".to_owned(),
+        short_name: "TN".to_owned(),
+    }
+}
+
+fn get_team_rankings() -> TeamRankings {
+    TeamRankings {
+        next_page: Some(2),
+        ranking: vec![TeamRanking {
+            members_count: 42,
+            mode: GameMode::Taiko,
+            playcount: 123_456,
+            pp: 98_765.4,
+            ranked_score: 1_000_000_000,
+            team: get_team(),
+        }],
+        total: 1234,
+    }
+}
+
 fn get_user() -> User {
     User {
         avatar_url: String::new(),
@@ -623,6 +718,18 @@ fn get_user() -> User {
             url: String::new(),
         }]),
         beatmap_playcounts_count: Some(3),
+        daily_challenge_stats: Some(DailyChallengeUserStats {
+            daily_streak_best: 12,
+            daily_streak_current: 3,
+            last_update: Some(get_date()),
+            last_weekly_streak: None,
+            playcount: 100,
+            top_10_percent_placements: 5,
+            top_50_percent_placements: 42,
+            user_id: 2,
+            weekly_streak_best: 8,
+            weekly_streak_current: 1,
+        }),
         favourite_mapset_count: Some(3),
         follower_count: Some(2),
         graveyard_mapset_count: Some(8),
@@ -669,6 +776,7 @@ fn get_user() -> User {
         scores_recent_count: Some(13),
         statistics: Some(get_user_stats()),
         support_level: Some(3),
+        team: Some(get_team()),
         pending_mapset_count: Some(13),
         medals: Some(vec![MedalCompact {
             achieved_at: get_date(),
@@ -735,6 +843,10 @@ fn get_user_compact() -> UserCompact {
         is_moderator: Some(false),
         is_nat: Some(false),
         is_silenced: Some(false),
+        kudosu: Some(UserKudosu {
+            available: 12,
+            total: 34,
+        }),
         loved_mapset_count: Some(34),
         medals: Some(vec![MedalCompact {
             achieved_at: get_date(),
@@ -760,6 +872,7 @@ fn get_user_compact() -> UserCompact {
         scores_recent_count: Some(34),
         statistics: Some(get_user_stats()),
         support_level: Some(1),
+        team: None,
         pending_mapset_count: Some(34),
     }
 }
@@ -845,6 +958,11 @@ fn serde_beatmap_attributes() {
     ser_de(&get_map_attributes());
 }
 
+#[test]
+fn serde_beatmap_scores() {
+    ser_de(&get_beatmap_scores());
+}
+
 #[test]
 fn serde_beatmapset_events() {
     ser_de(&get_mapset_events());
@@ -865,21 +983,41 @@ fn serde_forum_posts() {
     ser_de(&get_forum_posts());
 }
 
+#[test]
+fn serde_kudosu_rankings() {
+    ser_de(&get_kudosu_rankings());
+}
+
 #[test]
 fn serde_match() {
     ser_de(&get_match());
 }
 
+#[test]
+fn serde_room() {
+    ser_de(&get_room());
+}
+
 #[test]
 fn serde_score() {
     ser_de(&get_score());
 }
 
+#[test]
+fn serde_search_result() {
+    ser_de(&get_search_result());
+}
+
 #[test]
 fn serde_seasonal_backgrounds() {
     ser_de(&get_seasonal_backgrounds());
 }
 
+#[test]
+fn serde_team_rankings() {
+    ser_de(&get_team_rankings());
+}
+
 #[test]
 fn serde_user() {
     ser_de(&get_user());
@@ -921,6 +1059,11 @@ mod rkyv_tests {
         ser_de(&get_map_attributes());
     }
 
+    #[test]
+    fn serde_beatmap_scores() {
+        ser_de(&get_beatmap_scores());
+    }
+
     #[test]
     fn serde_beatmapset_events() {
         ser_de(&get_mapset_events());
@@ -942,11 +1085,21 @@ mod rkyv_tests {
     //     ser_de(&get_forum_posts());
     // }
 
+    #[test]
+    fn serde_kudosu_rankings() {
+        ser_de(&get_kudosu_rankings());
+    }
+
     #[test]
     fn serde_match() {
         ser_de(&get_match());
     }
 
+    #[test]
+    fn serde_room() {
+        ser_de(&get_room());
+    }
+
     #[test]
     fn serde_score() {
         ser_de(&get_score());
@@ -957,6 +1110,11 @@ mod rkyv_tests {
         ser_de(&get_seasonal_backgrounds());
     }
 
+    #[test]
+    fn serde_team_rankings() {
+        ser_de(&get_team_rankings());
+    }
+
     #[test]
     fn serde_user() {
         ser_de(&get_user());